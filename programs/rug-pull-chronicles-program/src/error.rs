@@ -16,6 +16,50 @@ pub enum RuggedError {
     DuplicateNFTMint,
     #[msg("Maximum supply limit for this collection has been reached.")]
     MaxSupplyExceeded,
+    #[msg("Verifier is suspended or not allowed to verify for this collection.")]
+    InvalidVerifier,
+    #[msg("Verifier name must be at most 32 bytes and scope must name known collections.")]
+    InvalidVerifierSettings,
+    #[msg("Victim attestation is invalid or lacks a matching Ed25519 signature check.")]
+    InvalidAttestation,
+    #[msg("Victim attestation has expired.")]
+    AttestationExpired,
+    #[msg("Victim attestation nonce has already been used.")]
+    AttestationReplayed,
+    #[msg("Compromised wallet has not authorized this destination.")]
+    DestinationNotAuthorized,
+    #[msg("Safe-destination authorization has expired or is valid for too long.")]
+    DestinationAuthorizationExpired,
+    #[msg("Safe-destination authorization nonce has already been used.")]
+    DestinationAuthorizationReplayed,
+    #[msg("Taxonomy entry not found, or label is empty, longer than 32 bytes or already taken.")]
+    InvalidTaxonomyEntry,
+    #[msg("Scammer profile accounts do not match the report's scammer addresses.")]
+    InvalidScammerProfile,
+    #[msg("Evidence entry has the wrong length for its kind, or the report is full.")]
+    InvalidEvidence,
+    #[msg("Report has already been approved or rejected.")]
+    ReportAlreadyModerated,
+    #[msg("Challenge is not open, its report can't be challenged, or its reason, URI or bond is invalid.")]
+    InvalidChallenge,
+    #[msg("Challenge resolution deadline has passed.")]
+    ChallengeDeadlinePassed,
+    #[msg("Challenge resolution deadline has not passed yet.")]
+    ChallengeDeadlineNotReached,
+    #[msg("Stake is below the collection's minimum report stake.")]
+    InvalidStake,
+    #[msg("Stake is locked until its approved lock period or its pending expiry is over.")]
+    StakeLocked,
+    #[msg("Report has an open challenge.")]
+    ReportChallenged,
+    #[msg("Collection is not under the program update authority, or its settings are invalid.")]
+    InvalidCollection,
+    #[msg("Collection is not accepting mints.")]
+    CollectionInactive,
+    #[msg("Collection slot is already bound; rebind it with update_config_collection.")]
+    CollectionSlotBound,
+    #[msg("Royalty creators must add up to 100 percent and allow lists can't be empty.")]
+    InvalidRoyalties,
     // add more as needed...
 }
 
@@ -24,9 +68,6 @@ pub enum CustomError {
     #[msg("The provided fee distribution is invalid. Treasury and anti-scam fee percentages must sum to 100")]
     InvalidFeeDistribution,
 
-    #[msg("The provided fee amount is too high. Maximum allowed is 50%")]
-    InvalidFeeAmount,
-
     #[msg("The provided minimum payment is too low. Minimum allowed is 0.01 SOL")]
     InvalidMinimumPayment,
 
@@ -35,4 +76,64 @@ pub enum CustomError {
 
     #[msg("The requested operation cannot be performed when the program is paused")]
     OperationNotAllowedWhenPaused,
+
+    #[msg("Withdrawal amount must be greater than zero")]
+    InvalidWithdrawalAmount,
+
+    #[msg("Withdrawal would leave the treasury below its rent-exempt minimum")]
+    InsufficientTreasuryBalance,
+
+    #[msg("Anti-scam treasury funds can only be moved through an approved disbursement")]
+    AntiscamTreasuryGoverned,
+
+    #[msg("The approver set is invalid. It must be non-empty, unique and at most 10 keys, with 1 <= threshold <= approvers")]
+    InvalidApproverSet,
+
+    #[msg("A disbursement council has already been set up for this config")]
+    DisbursementCouncilExists,
+
+    #[msg("The disbursement memo is too long. Maximum allowed is 200 bytes")]
+    DisbursementMemoTooLong,
+
+    #[msg("The execution time is earlier than the council's minimum delay allows")]
+    InvalidDisbursementTimelock,
+
+    #[msg("The disbursement is no longer pending")]
+    DisbursementNotPending,
+
+    #[msg("The approver has already approved this disbursement")]
+    DisbursementAlreadyApproved,
+
+    #[msg("The disbursement does not have enough approvals yet")]
+    InsufficientApprovals,
+
+    #[msg("The disbursement timelock has not expired yet")]
+    DisbursementTimelocked,
+
+    #[msg("The payment mint is not accepted or has been disabled")]
+    PaymentMintNotAccepted,
+
+    #[msg("Token payments require the payment mint, accepted mint record, token accounts and token program")]
+    IncompleteTokenPayment,
+
+    #[msg("Treasury token account is not the treasury PDA's associated token account for this mint")]
+    InvalidTreasuryTokenAccount,
+
+    #[msg("The payment amount is below the minimum payment for this payment option")]
+    PaymentBelowMinimum,
+
+    #[msg("The fee schedule is invalid. It needs 1-8 unique beneficiaries whose weights sum to 10,000 basis points")]
+    InvalidFeeSchedule,
+
+    #[msg("The configured fee schedule must be passed to the mint instruction")]
+    FeeScheduleRequired,
+
+    #[msg("Fee beneficiary accounts do not match the fee schedule")]
+    InvalidFeeBeneficiary,
+
+    #[msg("Token-2022 mints with a transfer fee can't be accepted for payments")]
+    TransferFeeMintNotSupported,
+
+    #[msg("The disbursement pays out a different currency than the accounts provided")]
+    DisbursementMintMismatch,
 }
@@ -0,0 +1,305 @@
+use crate::state::{
+    ChallengeStatus, CollectionFeeOverrides, CollectionKind, RejectionReason, ReportStatus,
+    TaxonomyKind,
+};
+use anchor_lang::prelude::*;
+
+/// Emitted whenever lamports leave one of the treasury PDAs
+#[event]
+pub struct TreasuryWithdrawn {
+    /// The treasury PDA the lamports were taken from
+    pub treasury: Pubkey,
+    /// Where the lamports were sent
+    pub recipient: Pubkey,
+    /// Lamports withdrawn in this instruction
+    pub amount: u64,
+    /// Lamports left in the treasury after the withdrawal
+    pub remaining_balance: u64,
+    /// Running total withdrawn from this treasury
+    pub total_withdrawn: u64,
+    /// Signer that triggered the withdrawal
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when SPL tokens are taken out of a treasury PDA's token account
+#[event]
+pub struct TreasuryTokenWithdrawn {
+    /// The treasury PDA owning the token account
+    pub treasury: Pubkey,
+    /// SPL mint withdrawn
+    pub mint: Pubkey,
+    /// Token account the tokens were sent to
+    pub recipient: Pubkey,
+    /// Base units withdrawn in this instruction
+    pub amount: u64,
+    /// Base units left in the treasury's token account after the withdrawal
+    pub remaining_balance: u64,
+    /// Running total withdrawn from this treasury for this mint
+    pub total_withdrawn: u64,
+    /// Signer that triggered the withdrawal
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an approver proposes an anti-scam treasury disbursement
+#[event]
+pub struct DisbursementProposed {
+    pub disbursement: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub recipient: Pubkey,
+    /// SPL mint to pay out (`None` for SOL)
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub execute_after: i64,
+}
+
+/// Emitted for every approval recorded on a disbursement
+#[event]
+pub struct DisbursementApproved {
+    pub disbursement: Pubkey,
+    pub approver: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+}
+
+/// Emitted when an approver cancels a pending disbursement
+#[event]
+pub struct DisbursementCancelled {
+    pub disbursement: Pubkey,
+    pub cancelled_by: Pubkey,
+}
+
+/// Emitted when a disbursement pays out of the anti-scam treasury
+#[event]
+pub struct DisbursementExecuted {
+    pub disbursement: Pubkey,
+    pub recipient: Pubkey,
+    /// SPL mint paid out (`None` for SOL)
+    pub mint: Option<Pubkey>,
+    pub amount: u64,
+    pub executed_by: Pubkey,
+}
+
+/// Emitted by the mint handlers with the exact breakdown of the payment
+#[event]
+pub struct MintPaymentCollected {
+    pub payer: Pubkey,
+    /// The asset being minted
+    pub asset: Pubkey,
+    /// SPL mint the payment was made in (`None` for SOL)
+    pub payment_mint: Option<Pubkey>,
+    /// Full amount paid by the user (lamports or token base units)
+    pub payment_amount: u64,
+    /// Portion sent to the general-ops treasury
+    pub treasury_amount: u64,
+    /// Portion sent to the anti-scam treasury
+    pub antiscam_amount: u64,
+    /// Every recipient paid by this mint, including any fee schedule beneficiaries
+    pub shares: Vec<FeeShare>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FeeShare {
+    /// Wallet or PDA credited (the token account owner for SPL payments)
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a wallet is verified as a scam victim
+#[event]
+pub struct VictimVerified {
+    pub wallet: Pubkey,
+    /// Verifier PDA that issued the verification
+    pub verifier: Pubkey,
+    pub verified_by: Pubkey,
+    pub verified_at: i64,
+}
+
+/// Emitted when the admin or the issuing verifier revokes a victim verification
+#[event]
+pub struct VictimVerificationRevoked {
+    pub wallet: Pubkey,
+    /// Verifier PDA that issued the verification
+    pub verifier: Pubkey,
+    pub revoked_by: Pubkey,
+}
+
+/// Emitted when the admin registers a verifier
+#[event]
+pub struct VerifierAdded {
+    pub verifier: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub scope: u8,
+}
+
+/// Emitted when a verifier is suspended or reinstated
+#[event]
+pub struct VerifierStatusChanged {
+    pub verifier: Pubkey,
+    pub active: bool,
+}
+
+/// Emitted when a verifier is removed, revoking every verification it issued
+#[event]
+pub struct VerifierRemoved {
+    pub verifier: Pubkey,
+    pub verifications_issued: u64,
+}
+
+/// Emitted when a mint consumes a signed victim attestation
+#[event]
+pub struct VictimAttestationUsed {
+    /// Verifier PDA whose key signed the attestation
+    pub verifier: Pubkey,
+    pub victim: Pubkey,
+    pub compromised_address: Pubkey,
+    pub nonce: u64,
+}
+
+/// Emitted when a scammed NFT is minted to a safe wallet on behalf of a compromised one
+#[event]
+pub struct SafeDestinationUsed {
+    pub asset: Pubkey,
+    pub compromised: Pubkey,
+    pub destination: Pubkey,
+}
+
+/// Emitted when the admin adds a platform category or attack type
+#[event]
+pub struct TaxonomyEntryAdded {
+    pub kind: TaxonomyKind,
+    pub id: u16,
+    pub label: String,
+}
+
+/// Emitted when a mint documents an incident, first or otherwise
+#[event]
+pub struct IncidentReported {
+    pub incident: Pubkey,
+    pub reporter: Pubkey,
+    pub asset: Pubkey,
+    /// Distinct wallets that have now documented the incident
+    pub reporter_count: u32,
+}
+
+/// Emitted when evidence is appended to a report after minting
+#[event]
+pub struct EvidenceAdded {
+    pub asset: Pubkey,
+    pub submitter: Pubkey,
+    pub entries_added: u8,
+    pub total_entries: u8,
+}
+
+/// Emitted when the admin appoints a moderator
+#[event]
+pub struct ModeratorAdded {
+    pub moderator: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// Emitted when the admin removes a moderator
+#[event]
+pub struct ModeratorRemoved {
+    pub moderator: Pubkey,
+    pub reports_moderated: u64,
+}
+
+/// Emitted when a moderator approves or rejects a pending report
+#[event]
+pub struct ReportModerated {
+    pub asset: Pubkey,
+    /// Moderator PDA that made the decision
+    pub moderator: Pubkey,
+    pub status: ReportStatus,
+    pub rejection_reason: Option<RejectionReason>,
+    /// Whether the rejected asset was burned rather than left frozen
+    pub burned: bool,
+}
+
+/// Emitted when someone posts a bond to challenge a report
+#[event]
+pub struct ChallengeOpened {
+    pub challenge: Pubkey,
+    pub asset: Pubkey,
+    pub challenger: Pubkey,
+    pub bond_lamports: u64,
+    pub resolution_deadline: i64,
+}
+
+/// Emitted when a challenge is upheld, rejected or expires
+#[event]
+pub struct ChallengeResolved {
+    pub challenge: Pubkey,
+    pub asset: Pubkey,
+    pub status: ChallengeStatus,
+    /// Who resolved it (default if it expired)
+    pub resolved_by: Pubkey,
+    pub bond_lamports: u64,
+}
+
+/// Emitted when a reporter stakes lamports on a report
+#[event]
+pub struct ReportStakeLocked {
+    pub asset: Pubkey,
+    pub reporter: Pubkey,
+    pub amount: u64,
+    pub unlock_at: i64,
+}
+
+/// Emitted when a stake is slashed to the anti-scam treasury
+#[event]
+pub struct ReportStakeSlashed {
+    pub asset: Pubkey,
+    pub reporter: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when a stake is returned to its reporter
+#[event]
+pub struct ReportStakeReleased {
+    pub asset: Pubkey,
+    pub reporter: Pubkey,
+    pub amount: u64,
+}
+
+/// Emitted when the admin adds a collection to the registry
+#[event]
+pub struct CollectionRegistered {
+    pub collection: Pubkey,
+    pub collection_record: Pubkey,
+    pub kind: CollectionKind,
+    pub schema_id: u16,
+}
+
+/// Emitted when the admin changes a registered collection's settings
+#[event]
+pub struct CollectionRecordUpdated {
+    pub collection: Pubkey,
+    pub max_supply: Option<u32>,
+    pub fee_overrides: CollectionFeeOverrides,
+    pub schema_id: u16,
+    pub active: bool,
+}
+
+/// Emitted when the admin moves a config collection slot to another collection
+#[event]
+pub struct CollectionRebound {
+    /// Which config slot moved
+    pub slot: CollectionKind,
+    pub old_collection: Pubkey,
+    pub new_collection: Pubkey,
+    /// Supply limit read from the new collection's MasterEdition plugin
+    pub max_supply: Option<u32>,
+}
+
+/// Emitted when the admin brings the config account up to the current layout
+#[event]
+pub struct ConfigMigrated {
+    pub config: Pubkey,
+    pub from_version: u16,
+    pub to_version: u16,
+}
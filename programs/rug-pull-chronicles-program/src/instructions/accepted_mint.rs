@@ -0,0 +1,170 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
+    token_2022::spl_token_2022::{
+        extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+        state::Mint as MintState,
+    },
+    token_interface::{Mint, TokenInterface},
+};
+
+#[derive(Accounts)]
+pub struct AddAcceptedMint<'info> {
+    /// The admin adding the payment option; pays for the record and token accounts
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The SPL mint to accept
+    #[account(mint::token_program = token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// The accepted-mint record
+    #[account(
+        init,
+        payer = admin,
+        space = AcceptedMint::INIT_SPACE,
+        seeds = [b"accepted_mint", config.key().as_ref(), payment_mint.key().as_ref()],
+        bump
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    /// General-ops treasury PDA, owner of the treasury token account
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(seeds = [b"treasury"], bump = config.treasury_bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Anti-scam treasury PDA, owner of the anti-scam token account
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(seeds = [b"treasury_anti_scam"], bump = config.antiscam_treasury_bump)]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// Treasury's associated token account for the payment mint
+    /// CHECK: Address is checked against the ATA derivation and created idempotently
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &treasury.key(),
+            &payment_mint.key(),
+            &token_program.key()
+        ) @ crate::error::CustomError::InvalidTreasuryTokenAccount
+    )]
+    pub treasury_token_account: UncheckedAccount<'info>,
+
+    /// Anti-scam treasury's associated token account for the payment mint
+    /// CHECK: Address is checked against the ATA derivation and created idempotently
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &antiscam_treasury.key(),
+            &payment_mint.key(),
+            &token_program.key()
+        ) @ crate::error::CustomError::InvalidTreasuryTokenAccount
+    )]
+    pub antiscam_token_account: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddAcceptedMint<'info> {
+    pub fn add_accepted_mint(&mut self, minimum_payment: u64, bump: u8) -> Result<()> {
+        require!(
+            minimum_payment > 0,
+            crate::error::CustomError::InvalidMinimumPayment
+        );
+
+        // Mint payments are split on the amount sent, so the treasuries must receive all of it
+        require!(
+            !self.has_transfer_fee()?,
+            crate::error::CustomError::TransferFeeMintNotSupported
+        );
+
+        // Anyone can create an ATA for any owner, so use the idempotent variant to
+        // avoid this instruction being blocked by a pre-created treasury token account
+        for (token_account, authority) in [
+            (&self.treasury_token_account, &self.treasury),
+            (&self.antiscam_token_account, &self.antiscam_treasury),
+        ] {
+            associated_token::create_idempotent(CpiContext::new(
+                self.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: self.admin.to_account_info(),
+                    associated_token: token_account.to_account_info(),
+                    authority: authority.to_account_info(),
+                    mint: self.payment_mint.to_account_info(),
+                    system_program: self.system_program.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                },
+            ))?;
+        }
+
+        self.accepted_mint.set_inner(AcceptedMint {
+            config: self.config.key(),
+            mint: self.payment_mint.key(),
+            token_program: self.token_program.key(),
+            minimum_payment,
+            enabled: true,
+            total_withdrawn_treasury: 0,
+            total_withdrawn_antiscam: 0,
+            bump,
+        });
+
+        Ok(())
+    }
+
+    /// Whether the payment mint is a Token-2022 mint with the TransferFeeConfig extension
+    fn has_transfer_fee(&self) -> Result<bool> {
+        let mint_info = self.payment_mint.to_account_info();
+        let data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+        Ok(mint
+            .get_extension_types()?
+            .contains(&ExtensionType::TransferFeeConfig))
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateAcceptedMint<'info> {
+    /// The admin updating the payment option
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The accepted-mint record to update
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [b"accepted_mint", config.key().as_ref(), accepted_mint.mint.as_ref()],
+        bump = accepted_mint.bump,
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+}
+
+impl<'info> UpdateAcceptedMint<'info> {
+    pub fn update_accepted_mint(&mut self, minimum_payment: u64, enabled: bool) -> Result<()> {
+        require!(
+            minimum_payment > 0,
+            crate::error::CustomError::InvalidMinimumPayment
+        );
+
+        self.accepted_mint.minimum_payment = minimum_payment;
+        self.accepted_mint.enabled = enabled;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
+use crate::error::RuggedError;
 use crate::state::config::Config;
 use anchor_lang::prelude::*;
 use mpl_core::{
-    instructions::AddCollectionPluginV1CpiBuilder,
+    instructions::{AddCollectionPluginV1CpiBuilder, UpdateCollectionPluginV1CpiBuilder},
     types::{Creator, Plugin, Royalties, RuleSet},
 };
 
@@ -9,6 +10,7 @@ use mpl_core::{
 pub struct AddCollectionPluginArgs {
     pub basis_points: u16,
     pub creators: Vec<CreatorInput>,
+    pub rule_set: RoyaltyRuleSet,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -17,6 +19,56 @@ pub struct CreatorInput {
     pub percentage: u8,
 }
 
+/// Which programs may transfer assets without paying royalties
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum RoyaltyRuleSet {
+    /// No enforcement
+    None,
+    /// Only these programs (e.g. royalty-paying marketplaces) can transfer assets
+    ProgramAllowList(Vec<Pubkey>),
+    /// These programs can't transfer assets
+    ProgramDenyList(Vec<Pubkey>),
+}
+
+/// Builds a Royalties plugin, checking it up front so a bad creator list fails
+/// with `InvalidRoyalties` rather than an opaque mpl-core error
+pub fn royalties_plugin(
+    basis_points: u16,
+    creators: Vec<CreatorInput>,
+    rule_set: RoyaltyRuleSet,
+) -> Result<Plugin> {
+    let total_percentage = creators.iter().map(|c| c.percentage as u16).sum::<u16>();
+    require!(
+        basis_points <= 10_000 && total_percentage == 100,
+        RuggedError::InvalidRoyalties
+    );
+
+    let rule_set = match rule_set {
+        RoyaltyRuleSet::None => RuleSet::None,
+        RoyaltyRuleSet::ProgramAllowList(programs) => {
+            // An empty allow list would block every transfer
+            require!(!programs.is_empty(), RuggedError::InvalidRoyalties);
+            RuleSet::ProgramAllowList(programs)
+        }
+        RoyaltyRuleSet::ProgramDenyList(programs) => RuleSet::ProgramDenyList(programs),
+    };
+
+    // Convert CreatorInput to mpl_core Creator type
+    let creators = creators
+        .into_iter()
+        .map(|c| Creator {
+            address: c.address,
+            percentage: c.percentage,
+        })
+        .collect::<Vec<Creator>>();
+
+    Ok(Plugin::Royalties(Royalties {
+        basis_points,
+        creators,
+        rule_set,
+    }))
+}
+
 #[derive(Accounts)]
 pub struct AddCollectionPlugin<'info> {
     /// The admin who can add collection royalties
@@ -58,15 +110,7 @@ impl<'info> AddCollectionPlugin<'info> {
         // Get PDA signer seeds for update_authority_pda
         let auth_seeds = &[b"upd_auth".as_ref(), &[self.config.update_authority_bump]];
 
-        // Convert CreatorInput to mpl_core Creator type
-        let creators = args
-            .creators
-            .iter()
-            .map(|c| Creator {
-                address: c.address,
-                percentage: c.percentage,
-            })
-            .collect::<Vec<Creator>>();
+        let plugin = royalties_plugin(args.basis_points, args.creators, args.rule_set)?;
 
         // Create the plugin add CPI
         AddCollectionPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
@@ -74,11 +118,24 @@ impl<'info> AddCollectionPlugin<'info> {
             .authority(Some(&self.update_authority_pda.to_account_info()))
             .payer(&self.admin.to_account_info())
             .system_program(&self.system_program.to_account_info())
-            .plugin(Plugin::Royalties(Royalties {
-                basis_points: args.basis_points,
-                creators,
-                rule_set: RuleSet::None,
-            }))
+            .plugin(plugin)
+            .invoke_signed(&[auth_seeds])?;
+        Ok(())
+    }
+
+    pub fn update_collection_royalties(&self, args: AddCollectionPluginArgs) -> Result<()> {
+        // Get PDA signer seeds for update_authority_pda
+        let auth_seeds = &[b"upd_auth".as_ref(), &[self.config.update_authority_bump]];
+
+        let plugin = royalties_plugin(args.basis_points, args.creators, args.rule_set)?;
+
+        // Replace the collection's existing Royalties plugin
+        UpdateCollectionPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .collection(&self.collection.to_account_info())
+            .authority(Some(&self.update_authority_pda.to_account_info()))
+            .payer(&self.admin.to_account_info())
+            .system_program(&self.system_program.to_account_info())
+            .plugin(plugin)
             .invoke_signed(&[auth_seeds])?;
         Ok(())
     }
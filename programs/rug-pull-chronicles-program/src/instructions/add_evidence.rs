@@ -0,0 +1,66 @@
+use crate::error::RuggedError;
+use crate::events::EvidenceAdded;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(entries: Vec<EvidenceEntryArgs>)]
+pub struct AddEvidence<'info> {
+    /// The report's reporter or the admin; pays for the extra space
+    #[account(mut)]
+    pub submitter: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The report the evidence supports
+    #[account(
+        seeds = [b"scam_report", scam_report.asset.as_ref()],
+        bump = scam_report.bump,
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// The report's evidence, grown to fit the new entries
+    #[account(
+        mut,
+        seeds = [b"evidence", scam_report.asset.as_ref()],
+        bump = evidence.bump,
+        realloc = evidence.space() + EvidenceEntry::space_for(&entries),
+        realloc::payer = submitter,
+        realloc::zero = false,
+    )]
+    pub evidence: Box<Account<'info, Evidence>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddEvidence<'info> {
+    pub fn add_evidence(&mut self, entries: Vec<EvidenceEntryArgs>) -> Result<()> {
+        let submitter = self.submitter.key();
+        require!(
+            submitter == self.scam_report.reporter || submitter == self.config.admin,
+            RuggedError::Unauthorized
+        );
+        require!(
+            !entries.is_empty() && self.evidence.can_append(&entries),
+            RuggedError::InvalidEvidence
+        );
+
+        let entries_added = entries.len() as u8;
+        self.evidence
+            .append(entries, submitter, Clock::get()?.unix_timestamp);
+
+        emit!(EvidenceAdded {
+            asset: self.scam_report.asset,
+            submitter,
+            entries_added,
+            total_entries: self.evidence.entries.len() as u8,
+        });
+
+        Ok(())
+    }
+}
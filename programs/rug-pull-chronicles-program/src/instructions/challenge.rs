@@ -0,0 +1,340 @@
+use crate::error::RuggedError;
+use crate::events::{ChallengeOpened, ChallengeResolved};
+use crate::state::*;
+use crate::utils::moderation::ModeratedAsset;
+use crate::utils::report_stake::slash_report_stake;
+use crate::utils::scammer_registry::update_scammer_profiles;
+use crate::utils::treasury::withdraw_from_treasury;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+#[derive(Accounts)]
+pub struct OpenChallenge<'info> {
+    /// Whoever disputes the report; pays for the account and posts the bond
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// The asset whose report is challenged
+    /// CHECK: Only used to derive the report and challenge addresses
+    pub asset: UncheckedAccount<'info>,
+
+    /// The report being challenged
+    #[account(
+        mut,
+        seeds = [b"scam_report", asset.key().as_ref()],
+        bump = scam_report.bump,
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// The challenge, holding the bond until it is resolved
+    #[account(
+        init,
+        payer = challenger,
+        space = Challenge::INIT_SPACE,
+        seeds = [b"challenge", asset.key().as_ref(), challenger.key().as_ref()],
+        bump
+    )]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OpenChallenge<'info> {
+    pub fn open_challenge(
+        &mut self,
+        reason: String,
+        evidence_uri: String,
+        bond_lamports: u64,
+        bump: u8,
+    ) -> Result<()> {
+        // Rejected or retracted reports are already down; there is nothing to dispute
+        require!(
+            matches!(
+                self.scam_report.status,
+                ReportStatus::Pending | ReportStatus::Approved
+            ),
+            RuggedError::InvalidChallenge
+        );
+        require!(
+            Challenge::is_valid(&reason, &evidence_uri, bond_lamports),
+            RuggedError::InvalidChallenge
+        );
+
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                Transfer {
+                    from: self.challenger.to_account_info(),
+                    to: self.challenge.to_account_info(),
+                },
+            ),
+            bond_lamports,
+        )?;
+
+        let opened_at = Clock::get()?.unix_timestamp;
+        let resolution_deadline = opened_at
+            .checked_add(CHALLENGE_RESOLUTION_PERIOD)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        self.challenge.set_inner(Challenge {
+            asset: self.asset.key(),
+            challenger: self.challenger.key(),
+            bond_lamports,
+            reason,
+            evidence_uri,
+            opened_at,
+            resolution_deadline,
+            status: ChallengeStatus::Open,
+            bump,
+        });
+        self.scam_report.add_challenge(resolution_deadline)?;
+
+        emit!(ChallengeOpened {
+            challenge: self.challenge.key(),
+            asset: self.asset.key(),
+            challenger: self.challenger.key(),
+            bond_lamports,
+            resolution_deadline,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ResolveChallenge<'info> {
+    /// A moderator's signing key or the admin; pays for any attribute growth
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The moderator resolving the challenge; omitted when the admin resolves it
+    #[account(
+        seeds = [b"moderator", authority.key().as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Option<Account<'info, Moderator>>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The asset whose report is challenged
+    /// CHECK: Validated by the MPL Core program when its attributes are updated
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// The challenged report
+    #[account(
+        mut,
+        seeds = [b"scam_report", asset.key().as_ref()],
+        bump = scam_report.bump,
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// The challenge to resolve, closed to the challenger
+    #[account(
+        mut,
+        close = challenger,
+        has_one = challenger,
+        seeds = [b"challenge", asset.key().as_ref(), challenge.challenger.as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// The reporter's stake, slashed if the challenge is upheld
+    /// CHECK: May not exist if the reporter didn't stake; read in the handler
+    #[account(
+        mut,
+        seeds = [b"report_stake", asset.key().as_ref()],
+        bump
+    )]
+    pub report_stake: UncheckedAccount<'info>,
+
+    /// Receives the bond back if the challenge is upheld, and the challenge's rent
+    /// CHECK: Checked against the challenge
+    #[account(mut)]
+    pub challenger: UncheckedAccount<'info>,
+
+    /// Receives the bond if the challenge is rejected, and the slashed stake if upheld
+    /// CHECK: This is the anti-scam treasury PDA
+    #[account(
+        mut,
+        seeds = [b"treasury_anti_scam"],
+        bump = config.antiscam_treasury_bump,
+    )]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// The collection the asset belongs to
+    /// CHECK: Must be one of the program's collections; validated by the MPL Core program
+    #[account(
+        mut,
+        constraint = collection.key() == config.standard_collection
+            || collection.key() == config.scammed_collection
+            || collection_record.is_some()
+            @ crate::error::RuggedError::Unauthorized
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Registry entry of `collection`, required unless it is bound to a config slot
+    #[account(
+        seeds = [b"collection_record", collection.key().as_ref()],
+        bump = collection_record.bump,
+    )]
+    pub collection_record: Option<Box<Account<'info, CollectionRecord>>>,
+
+    /// The program's update authority PDA, which can update the asset's attributes
+    /// CHECK: This is a PDA that only this program can sign for
+    #[account(
+        seeds = [b"upd_auth"],
+        bump = config.update_authority_bump,
+    )]
+    pub update_authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: This is the ID of the Metaplex Core program
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveChallenge<'info> {
+    /// Upholding retracts the report and refunds the bond; rejecting sends the bond
+    /// to the anti-scam treasury. A retracted report is taken out of its scammer
+    /// profiles, passed in `profile_accounts` in the report's order.
+    pub fn resolve_challenge(
+        &mut self,
+        profile_accounts: &[AccountInfo],
+        upheld: bool,
+    ) -> Result<()> {
+        require!(
+            self.moderator.is_some() || self.authority.key() == self.config.admin,
+            RuggedError::Unauthorized
+        );
+        require!(
+            self.challenge.status == ChallengeStatus::Open,
+            RuggedError::InvalidChallenge
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now <= self.challenge.resolution_deadline,
+            RuggedError::ChallengeDeadlinePassed
+        );
+
+        let bond_recipient = if upheld {
+            // A report rejected while the challenge was open is already down, and may
+            // have been burned with its attributes
+            if self.scam_report.status != ReportStatus::Rejected {
+                let approved = self.scam_report.status == ReportStatus::Approved;
+                self.scam_report.status = ReportStatus::Retracted;
+                let report = &self.scam_report;
+                update_scammer_profiles(profile_accounts, report, |profile| {
+                    profile.remove_report(report, approved);
+                    Ok(())
+                })?;
+
+                let asset = ModeratedAsset {
+                    asset: self.asset.as_ref(),
+                    collection: self.collection.as_ref(),
+                    update_authority: self.update_authority_pda.as_ref(),
+                    update_authority_bump: self.config.update_authority_bump,
+                    payer: self.authority.as_ref(),
+                    system_program: self.system_program.as_ref(),
+                    mpl_core_program: self.mpl_core_program.as_ref(),
+                };
+                // Approval thawed the asset; a false report goes back to frozen
+                if approved {
+                    asset.set_frozen(true)?;
+                }
+                asset.set_status_attributes(ReportStatus::Retracted, None)?;
+            }
+            // A retracted report was a false report
+            slash_report_stake(
+                &self.report_stake.to_account_info(),
+                &self.antiscam_treasury.to_account_info(),
+            )?;
+            self.challenger.to_account_info()
+        } else {
+            self.antiscam_treasury.to_account_info()
+        };
+        withdraw_from_treasury(
+            &self.challenge.to_account_info(),
+            &bond_recipient,
+            self.challenge.bond_lamports,
+        )?;
+
+        let status = if upheld {
+            ChallengeStatus::Upheld
+        } else {
+            ChallengeStatus::Rejected
+        };
+        self.scam_report.remove_challenge();
+
+        emit!(ChallengeResolved {
+            challenge: self.challenge.key(),
+            asset: self.challenge.asset,
+            status,
+            resolved_by: self.authority.key(),
+            bond_lamports: self.challenge.bond_lamports,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExpireChallenge<'info> {
+    /// The challenger reclaiming the bond
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+
+    /// The unresolved challenge, closed to the challenger
+    #[account(
+        mut,
+        close = challenger,
+        has_one = challenger,
+        seeds = [b"challenge", challenge.asset.as_ref(), challenger.key().as_ref()],
+        bump = challenge.bump,
+    )]
+    pub challenge: Box<Account<'info, Challenge>>,
+
+    /// The challenged report
+    #[account(
+        mut,
+        seeds = [b"scam_report", challenge.asset.as_ref()],
+        bump = scam_report.bump,
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+}
+
+impl<'info> ExpireChallenge<'info> {
+    /// Refunds the bond of a challenge nobody resolved before its deadline
+    pub fn expire_challenge(&mut self) -> Result<()> {
+        require!(
+            self.challenge.status == ChallengeStatus::Open,
+            RuggedError::InvalidChallenge
+        );
+        require!(
+            Clock::get()?.unix_timestamp > self.challenge.resolution_deadline,
+            RuggedError::ChallengeDeadlineNotReached
+        );
+
+        withdraw_from_treasury(
+            &self.challenge.to_account_info(),
+            &self.challenger.to_account_info(),
+            self.challenge.bond_lamports,
+        )?;
+        self.scam_report.remove_challenge();
+
+        emit!(ChallengeResolved {
+            challenge: self.challenge.key(),
+            asset: self.challenge.asset,
+            status: ChallengeStatus::Expired,
+            resolved_by: Pubkey::default(),
+            bond_lamports: self.challenge.bond_lamports,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(chain_id: u64, address: Pubkey)]
+pub struct CheckAddress<'info> {
+    /// Profile of the address being checked. It may not exist yet, in which case the
+    /// address has never been reported.
+    /// CHECK: Address is checked against the seeds; contents are read in the handler
+    #[account(
+        seeds = [b"scammer", chain_id.to_le_bytes().as_ref(), address.as_ref()],
+        bump
+    )]
+    pub scammer_profile: UncheckedAccount<'info>,
+}
+
+impl<'info> CheckAddress<'info> {
+    /// Read-only risk lookup, meant to be called through CPI by other programs.
+    /// The summary is returned through the transaction's return data.
+    pub fn check_address(&self) -> Result<AddressRiskSummary> {
+        let account = self.scammer_profile.to_account_info();
+        if *account.owner != crate::ID {
+            return Ok(AddressRiskSummary::default());
+        }
+
+        let profile = ScammerProfile::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        Ok(AddressRiskSummary::from(&profile))
+    }
+}
@@ -0,0 +1,110 @@
+use crate::error::RuggedError;
+use crate::events::{CollectionRecordUpdated, CollectionRegistered};
+use crate::state::*;
+use crate::utils::collection::load_program_collection;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RegisterCollection<'info> {
+    /// The admin registering the collection; pays for the record
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The mpl-core collection, which must already be under the `upd_auth` PDA
+    /// CHECK: Deserialized and checked in the handler
+    pub collection: UncheckedAccount<'info>,
+
+    /// Registry entry for the collection
+    #[account(
+        init,
+        payer = admin,
+        space = CollectionRecord::INIT_SPACE,
+        seeds = [b"collection_record", collection.key().as_ref()],
+        bump
+    )]
+    pub collection_record: Account<'info, CollectionRecord>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterCollection<'info> {
+    pub fn register_collection(
+        &mut self,
+        kind: CollectionKind,
+        settings: CollectionSettings,
+        bump: u8,
+    ) -> Result<()> {
+        load_program_collection(&self.collection, &self.config.update_authority)?;
+
+        let mut record = CollectionRecord {
+            collection: self.collection.key(),
+            kind,
+            max_supply: None,
+            minted: 0,
+            fee_overrides: CollectionFeeOverrides::default(),
+            active: false,
+            schema_id: 0,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump,
+        };
+        require!(record.apply(settings), RuggedError::InvalidCollection);
+        self.collection_record.set_inner(record);
+
+        emit!(CollectionRegistered {
+            collection: self.collection.key(),
+            collection_record: self.collection_record.key(),
+            kind,
+            schema_id: settings.schema_id,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollectionRecord<'info> {
+    /// The admin changing the collection's settings
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Registry entry to update
+    #[account(
+        mut,
+        seeds = [b"collection_record", collection_record.collection.as_ref()],
+        bump = collection_record.bump,
+    )]
+    pub collection_record: Account<'info, CollectionRecord>,
+}
+
+impl<'info> UpdateCollectionRecord<'info> {
+    pub fn update_collection_record(&mut self, settings: CollectionSettings) -> Result<()> {
+        require!(
+            self.collection_record.apply(settings),
+            RuggedError::InvalidCollection
+        );
+
+        emit!(CollectionRecordUpdated {
+            collection: self.collection_record.collection,
+            max_supply: settings.max_supply,
+            fee_overrides: settings.fee_overrides,
+            schema_id: settings.schema_id,
+            active: settings.active,
+        });
+
+        Ok(())
+    }
+}
@@ -1,4 +1,10 @@
+use crate::error::RuggedError;
+use crate::events::CollectionRegistered;
+use crate::state::collection::{
+    CollectionFeeOverrides, CollectionKind, CollectionRecord, CollectionSettings, CollectionTarget,
+};
 use crate::state::config::Config;
+use crate::utils::pda::create_pda_account;
 use anchor_lang::prelude::*;
 use mpl_core::instructions::CreateCollectionV2CpiBuilder;
 use mpl_core::types::{MasterEdition, Plugin, PluginAuthorityPair};
@@ -11,14 +17,18 @@ pub struct CreateCollectionArgs {
     pub max_supply: Option<u32>,
     pub edition_name: Option<String>,
     pub edition_uri: Option<String>,
+    /// Config slot or registry entry the new collection is bound to
+    pub target: CollectionTarget,
 }
 
 #[derive(Accounts)]
 pub struct CreateCollection<'info> {
     #[account(mut)]
     pub collection: Signer<'info>,
-    /// CHECK: this account will be checked by the mpl_core program
-    pub update_authority: Option<UncheckedAccount<'info>>,
+    /// The program's `upd_auth` PDA, always set as the collection's update authority
+    /// CHECK: Address is checked against the config's bump
+    #[account(seeds = [b"upd_auth"], bump = config.update_authority_bump)]
+    pub update_authority: UncheckedAccount<'info>,
     #[account(mut, constraint = payer.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
@@ -27,12 +37,37 @@ pub struct CreateCollection<'info> {
     pub mpl_core_program: UncheckedAccount<'info>,
 
     /// Config account to store the collection address
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
     pub config: Account<'info, Config>,
+
+    /// Registry entry for the collection, required for the `Registry` target
+    /// CHECK: Created in the handler
+    #[account(
+        mut,
+        seeds = [b"collection_record", collection.key().as_ref()],
+        bump
+    )]
+    pub collection_record: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> CreateCollection<'info> {
-    pub fn create_core_collection(&mut self, args: CreateCollectionArgs) -> Result<()> {
+    pub fn create_core_collection(
+        &mut self,
+        args: CreateCollectionArgs,
+        bumps: &CreateCollectionBumps,
+    ) -> Result<()> {
+        // Bound config slots can only be rebound through `update_config_collection`
+        let bound = match args.target {
+            CollectionTarget::Standard => self.config.standard_collection,
+            CollectionTarget::Scammed => self.config.scammed_collection,
+            CollectionTarget::Registry { .. } => Pubkey::default(),
+        };
+        require_keys_eq!(bound, Pubkey::default(), RuggedError::CollectionSlotBound);
+
         // Create the Master Edition plugin if max_supply is provided
         let plugins = if args.max_supply.is_some()
             || args.edition_name.is_some()
@@ -60,11 +95,7 @@ impl<'info> CreateCollection<'info> {
         let system_program_info = self.system_program.to_account_info();
         let core_program_info = self.mpl_core_program.to_account_info();
 
-        // Get update authority if it exists
-        let update_authority_info = self
-            .update_authority
-            .as_ref()
-            .map(|auth| auth.to_account_info());
+        let update_authority_info = self.update_authority.to_account_info();
 
         // Build the instruction step by step
         let mut builder = CreateCollectionV2CpiBuilder::new(&core_program_info);
@@ -72,12 +103,8 @@ impl<'info> CreateCollection<'info> {
         let builder = builder.payer(&payer_info);
         let builder = builder.system_program(&system_program_info);
         let builder = builder.name(args.name);
-        let mut builder = builder.uri(args.uri);
-
-        // Add update_authority if it exists
-        if let Some(auth_info) = update_authority_info.as_ref() {
-            builder = builder.update_authority(Some(auth_info));
-        }
+        let builder = builder.uri(args.uri);
+        let mut builder = builder.update_authority(Some(&update_authority_info));
 
         // Add plugins if any
         if !plugins.is_empty() {
@@ -87,18 +114,70 @@ impl<'info> CreateCollection<'info> {
         // Invoke the instruction
         builder.invoke()?;
 
-        // Update the config to track Master Edition status
-        if args.max_supply.is_some() {
-            if self.config.standard_collection == self.collection.key() {
-                self.config.standard_collection_has_master_edition = true;
+        // Bind the collection to its target along with its supply limit
+        match args.target {
+            CollectionTarget::Standard => {
+                self.config.standard_collection = self.collection.key();
+                self.config.standard_collection_has_master_edition = args.max_supply.is_some();
                 self.config.standard_collection_max_supply = args.max_supply;
-            } else if self.config.scammed_collection == self.collection.key() {
-                self.config.scammed_collection_has_master_edition = true;
+            }
+            CollectionTarget::Scammed => {
+                self.config.scammed_collection = self.collection.key();
+                self.config.scammed_collection_has_master_edition = args.max_supply.is_some();
                 self.config.scammed_collection_max_supply = args.max_supply;
             }
+            CollectionTarget::Registry { kind, mut settings } => {
+                settings.max_supply = settings.max_supply.or(args.max_supply);
+                self.register(kind, settings, bumps)?;
+            }
         }
 
         // Return success
         Ok(())
     }
+
+    /// Creates the collection's `CollectionRecord`, as `register_collection` would
+    fn register(
+        &self,
+        kind: CollectionKind,
+        settings: CollectionSettings,
+        bumps: &CreateCollectionBumps,
+    ) -> Result<()> {
+        let (Some(record_account), Some(bump)) = (&self.collection_record, bumps.collection_record)
+        else {
+            return err!(RuggedError::InvalidCollection);
+        };
+
+        let mut record = CollectionRecord {
+            collection: self.collection.key(),
+            kind,
+            max_supply: None,
+            minted: 0,
+            fee_overrides: CollectionFeeOverrides::default(),
+            active: false,
+            schema_id: 0,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump,
+        };
+        require!(record.apply(settings), RuggedError::InvalidCollection);
+
+        let collection_key = self.collection.key();
+        create_pda_account(
+            &self.payer.to_account_info(),
+            record_account,
+            &self.system_program.to_account_info(),
+            CollectionRecord::INIT_SPACE,
+            &[b"collection_record", collection_key.as_ref(), &[bump]],
+        )?;
+        record.try_serialize(&mut &mut record_account.try_borrow_mut_data()?[..])?;
+
+        emit!(CollectionRegistered {
+            collection: collection_key,
+            collection_record: record_account.key(),
+            kind,
+            schema_id: settings.schema_id,
+        });
+
+        Ok(())
+    }
 }
@@ -0,0 +1,401 @@
+use crate::error::CustomError;
+use crate::events::{
+    DisbursementApproved, DisbursementCancelled, DisbursementExecuted, DisbursementProposed,
+    TreasuryTokenWithdrawn, TreasuryWithdrawn,
+};
+use crate::state::*;
+use crate::utils::treasury::{withdraw_from_treasury, withdraw_token_from_treasury};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ProposeDisbursement<'info> {
+    /// Approver creating the proposal; pays for the proposal account
+    #[account(mut, constraint = council.is_approver(&proposer.key()) @ crate::error::RuggedError::Unauthorized)]
+    pub proposer: Signer<'info>,
+
+    /// The council governing the anti-scam treasury
+    #[account(
+        mut,
+        seeds = [b"disbursement_council", council.config.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+
+    /// The new proposal, keyed by the council's proposal counter
+    #[account(
+        init,
+        payer = proposer,
+        space = Disbursement::INIT_SPACE,
+        seeds = [
+            b"disbursement",
+            council.key().as_ref(),
+            council.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub disbursement: Account<'info, Disbursement>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ProposeDisbursement<'info> {
+    pub fn propose_disbursement(
+        &mut self,
+        recipient: Pubkey,
+        mint: Option<Pubkey>,
+        amount: u64,
+        memo: String,
+        execute_after: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(amount > 0, CustomError::InvalidWithdrawalAmount);
+        require!(
+            memo.len() <= MAX_DISBURSEMENT_MEMO_LEN,
+            CustomError::DisbursementMemoTooLong
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let earliest = now
+            .checked_add(self.council.min_delay)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+        require!(
+            execute_after >= earliest,
+            CustomError::InvalidDisbursementTimelock
+        );
+
+        let id = self.council.proposal_count;
+        self.disbursement.set_inner(Disbursement {
+            council: self.council.key(),
+            id,
+            proposer: self.proposer.key(),
+            recipient,
+            mint,
+            amount,
+            memo,
+            execute_after,
+            // Proposing counts as the proposer's approval
+            approvals: vec![self.proposer.key()],
+            status: DisbursementStatus::Pending,
+            created_at: now,
+            resolved_at: 0,
+            resolved_by: Pubkey::default(),
+            bump,
+        });
+
+        self.council.proposal_count = id
+            .checked_add(1)
+            .ok_or(CustomError::ArithmeticOverflow)?;
+
+        emit!(DisbursementProposed {
+            disbursement: self.disbursement.key(),
+            id,
+            proposer: self.proposer.key(),
+            recipient,
+            mint,
+            amount,
+            execute_after,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ApproveDisbursement<'info> {
+    #[account(constraint = council.is_approver(&approver.key()) @ crate::error::RuggedError::Unauthorized)]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"disbursement_council", council.config.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+
+    #[account(
+        mut,
+        has_one = council,
+        seeds = [b"disbursement", council.key().as_ref(), disbursement.id.to_le_bytes().as_ref()],
+        bump = disbursement.bump,
+    )]
+    pub disbursement: Account<'info, Disbursement>,
+}
+
+impl<'info> ApproveDisbursement<'info> {
+    pub fn approve_disbursement(&mut self) -> Result<()> {
+        require!(
+            self.disbursement.status == DisbursementStatus::Pending,
+            CustomError::DisbursementNotPending
+        );
+        require!(
+            !self.disbursement.approvals.contains(&self.approver.key()),
+            CustomError::DisbursementAlreadyApproved
+        );
+
+        // Drop approvals from keys rotated out of the council so the list stays bounded
+        let council = &self.council;
+        self.disbursement
+            .approvals
+            .retain(|key| council.is_approver(key));
+        self.disbursement.approvals.push(self.approver.key());
+
+        emit!(DisbursementApproved {
+            disbursement: self.disbursement.key(),
+            approver: self.approver.key(),
+            approvals: self.disbursement.valid_approvals(&self.council),
+            threshold: self.council.threshold,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct CancelDisbursement<'info> {
+    #[account(constraint = council.is_approver(&approver.key()) @ crate::error::RuggedError::Unauthorized)]
+    pub approver: Signer<'info>,
+
+    #[account(
+        seeds = [b"disbursement_council", council.config.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+
+    #[account(
+        mut,
+        has_one = council,
+        seeds = [b"disbursement", council.key().as_ref(), disbursement.id.to_le_bytes().as_ref()],
+        bump = disbursement.bump,
+    )]
+    pub disbursement: Account<'info, Disbursement>,
+}
+
+impl<'info> CancelDisbursement<'info> {
+    pub fn cancel_disbursement(&mut self) -> Result<()> {
+        require!(
+            self.disbursement.status == DisbursementStatus::Pending,
+            CustomError::DisbursementNotPending
+        );
+
+        // The account is kept around as an audit record
+        self.disbursement.status = DisbursementStatus::Cancelled;
+        self.disbursement.resolved_at = Clock::get()?.unix_timestamp;
+        self.disbursement.resolved_by = self.approver.key();
+
+        emit!(DisbursementCancelled {
+            disbursement: self.disbursement.key(),
+            cancelled_by: self.approver.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecuteDisbursement<'info> {
+    /// Anyone may execute once the timelock has passed and enough approvals are in
+    pub executor: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+        constraint = config.disbursement_council == council.key() @ crate::error::RuggedError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"disbursement_council", council.config.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+
+    #[account(
+        mut,
+        has_one = council,
+        has_one = recipient,
+        seeds = [b"disbursement", council.key().as_ref(), disbursement.id.to_le_bytes().as_ref()],
+        bump = disbursement.bump,
+    )]
+    pub disbursement: Account<'info, Disbursement>,
+
+    /// Anti-scam treasury PDA
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(
+        mut,
+        seeds = [b"treasury_anti_scam"],
+        bump = config.antiscam_treasury_bump,
+    )]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// Recipient named in the proposal
+    /// CHECK: Verified against the disbursement record
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> ExecuteDisbursement<'info> {
+    pub fn execute_disbursement(&mut self) -> Result<()> {
+        require!(
+            self.disbursement.mint.is_none(),
+            CustomError::DisbursementMintMismatch
+        );
+        let now = self.disbursement.check_executable(&self.council)?;
+
+        let amount = self.disbursement.amount;
+        let remaining_balance = withdraw_from_treasury(
+            &self.antiscam_treasury.to_account_info(),
+            &self.recipient.to_account_info(),
+            amount,
+        )?;
+
+        self.config.total_withdrawn_antiscam = self
+            .config
+            .total_withdrawn_antiscam
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.disbursement.status = DisbursementStatus::Executed;
+        self.disbursement.resolved_at = now;
+        self.disbursement.resolved_by = self.executor.key();
+
+        emit!(TreasuryWithdrawn {
+            treasury: self.antiscam_treasury.key(),
+            recipient: self.recipient.key(),
+            amount,
+            remaining_balance,
+            total_withdrawn: self.config.total_withdrawn_antiscam,
+            authority: self.executor.key(),
+            timestamp: now,
+        });
+        emit!(DisbursementExecuted {
+            disbursement: self.disbursement.key(),
+            recipient: self.recipient.key(),
+            mint: None,
+            amount,
+            executed_by: self.executor.key(),
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTokenDisbursement<'info> {
+    /// Anyone may execute once the timelock has passed and enough approvals are in
+    pub executor: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+        constraint = config.disbursement_council == council.key() @ crate::error::RuggedError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        seeds = [b"disbursement_council", council.config.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+
+    #[account(
+        mut,
+        has_one = council,
+        has_one = recipient,
+        constraint = disbursement.mint == Some(payment_mint.key())
+            @ CustomError::DisbursementMintMismatch,
+        seeds = [b"disbursement", council.key().as_ref(), disbursement.id.to_le_bytes().as_ref()],
+        bump = disbursement.bump,
+    )]
+    pub disbursement: Account<'info, Disbursement>,
+
+    /// The SPL mint paid out
+    #[account(mint::token_program = token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// The accepted-mint record, holding the mint's withdrawn totals
+    #[account(
+        mut,
+        constraint = accepted_mint.token_program == token_program.key()
+            @ CustomError::PaymentMintNotAccepted,
+        seeds = [b"accepted_mint", config.key().as_ref(), payment_mint.key().as_ref()],
+        bump = accepted_mint.bump,
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    /// Anti-scam treasury PDA, owner of the anti-scam token account
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(
+        seeds = [b"treasury_anti_scam"],
+        bump = config.antiscam_treasury_bump,
+    )]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// Anti-scam treasury's associated token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = antiscam_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub antiscam_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account named in the proposal
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ExecuteTokenDisbursement<'info> {
+    pub fn execute_token_disbursement(&mut self) -> Result<()> {
+        let now = self.disbursement.check_executable(&self.council)?;
+
+        let amount = self.disbursement.amount;
+        let remaining_balance = withdraw_token_from_treasury(
+            &self.antiscam_treasury.to_account_info(),
+            &[b"treasury_anti_scam", &[self.config.antiscam_treasury_bump]],
+            &mut self.antiscam_token_account,
+            &self.recipient,
+            &self.payment_mint,
+            &self.token_program,
+            amount,
+        )?;
+
+        self.accepted_mint.total_withdrawn_antiscam = self
+            .accepted_mint
+            .total_withdrawn_antiscam
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        self.disbursement.status = DisbursementStatus::Executed;
+        self.disbursement.resolved_at = now;
+        self.disbursement.resolved_by = self.executor.key();
+
+        emit!(TreasuryTokenWithdrawn {
+            treasury: self.antiscam_treasury.key(),
+            mint: self.payment_mint.key(),
+            recipient: self.recipient.key(),
+            amount,
+            remaining_balance,
+            total_withdrawn: self.accepted_mint.total_withdrawn_antiscam,
+            authority: self.executor.key(),
+            timestamp: now,
+        });
+        emit!(DisbursementExecuted {
+            disbursement: self.disbursement.key(),
+            recipient: self.recipient.key(),
+            mint: Some(self.payment_mint.key()),
+            amount,
+            executed_by: self.executor.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::error::CustomError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitDisbursementCouncil<'info> {
+    /// The admin handing control of the anti-scam treasury to the council
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+        constraint = config.disbursement_council == Pubkey::default()
+            @ CustomError::DisbursementCouncilExists,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The council record
+    #[account(
+        init,
+        payer = admin,
+        space = DisbursementCouncil::INIT_SPACE,
+        seeds = [b"disbursement_council", config.key().as_ref()],
+        bump
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitDisbursementCouncil<'info> {
+    pub fn init_disbursement_council(
+        &mut self,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            DisbursementCouncil::is_valid_approver_set(&approvers, threshold) && min_delay >= 0,
+            CustomError::InvalidApproverSet
+        );
+
+        self.council.set_inner(DisbursementCouncil {
+            config: self.config.key(),
+            approvers,
+            threshold,
+            min_delay,
+            proposal_count: 0,
+            bump,
+        });
+
+        // From now on `withdraw_antiscam` is disabled and funds move only via disbursements
+        self.config.disbursement_council = self.council.key();
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateDisbursementCouncil<'info> {
+    /// Any current approver may submit the rotation; co-signers are passed as remaining accounts
+    #[account(constraint = council.is_approver(&approver.key()) @ crate::error::RuggedError::Unauthorized)]
+    pub approver: Signer<'info>,
+
+    /// The council to rotate
+    #[account(
+        mut,
+        seeds = [b"disbursement_council", council.config.as_ref()],
+        bump = council.bump,
+    )]
+    pub council: Account<'info, DisbursementCouncil>,
+}
+
+impl<'info> UpdateDisbursementCouncil<'info> {
+    /// Replaces the approver set. Requires `threshold` signatures from the *current* set:
+    /// the submitting approver plus any co-signing approvers in `remaining_accounts`.
+    pub fn update_disbursement_council(
+        &mut self,
+        remaining_accounts: &[AccountInfo],
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+    ) -> Result<()> {
+        require!(
+            DisbursementCouncil::is_valid_approver_set(&approvers, threshold) && min_delay >= 0,
+            CustomError::InvalidApproverSet
+        );
+
+        // Count distinct current approvers that signed this transaction
+        let mut signers = vec![self.approver.key()];
+        for account in remaining_accounts {
+            if account.is_signer
+                && self.council.is_approver(account.key)
+                && !signers.contains(account.key)
+            {
+                signers.push(*account.key);
+            }
+        }
+        require!(
+            signers.len() >= self.council.threshold as usize,
+            CustomError::InsufficientApprovals
+        );
+
+        self.council.approvers = approvers;
+        self.council.threshold = threshold;
+        self.council.min_delay = min_delay;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::CustomError;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitFeeSchedule<'info> {
+    /// The admin creating the schedule
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The fee schedule, one per config
+    #[account(
+        init,
+        payer = admin,
+        space = FeeSchedule::INIT_SPACE,
+        seeds = [b"fee_schedule", config.key().as_ref()],
+        bump
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitFeeSchedule<'info> {
+    pub fn init_fee_schedule(
+        &mut self,
+        beneficiaries: Vec<FeeBeneficiary>,
+        remainder_index: u8,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            FeeSchedule::is_valid(&beneficiaries, remainder_index),
+            CustomError::InvalidFeeSchedule
+        );
+
+        self.fee_schedule.set_inner(FeeSchedule {
+            config: self.config.key(),
+            beneficiaries,
+            remainder_index,
+            active: true,
+            bump,
+        });
+
+        // Mint handlers must pass the schedule from now on
+        self.config.fee_schedule = self.fee_schedule.key();
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateFeeSchedule<'info> {
+    /// The admin updating the schedule
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The fee schedule to update
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [b"fee_schedule", config.key().as_ref()],
+        bump = fee_schedule.bump,
+    )]
+    pub fee_schedule: Account<'info, FeeSchedule>,
+}
+
+impl<'info> UpdateFeeSchedule<'info> {
+    pub fn update_fee_schedule(
+        &mut self,
+        beneficiaries: Vec<FeeBeneficiary>,
+        remainder_index: u8,
+        active: bool,
+    ) -> Result<()> {
+        require!(
+            FeeSchedule::is_valid(&beneficiaries, remainder_index),
+            CustomError::InvalidFeeSchedule
+        );
+
+        self.fee_schedule.beneficiaries = beneficiaries;
+        self.fee_schedule.remainder_index = remainder_index;
+        self.fee_schedule.active = active;
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::state::config::Config;
+use crate::state::config::{Config, FeeRemainderSide, CONFIG_VERSION};
 use anchor_lang::prelude::*;
 use mpl_core;
 
@@ -66,8 +66,7 @@ impl<'info> Initialize<'info> {
         // These will be updated later when the collections are created
         let default_collection = Pubkey::default();
 
-        // Default fee settings: 5% mint fee, split 60/40 between treasury and anti-scam treasury
-        let mint_fee_basis_points = 500; // 5%
+        // Default fee settings: payments split 60/40 between treasury and anti-scam treasury
         let treasury_fee_percent = 60; // 60% to regular treasury
         let antiscam_fee_percent = 40; // 40% to anti-scam treasury
 
@@ -92,18 +91,26 @@ impl<'info> Initialize<'info> {
             antiscam_treasury: self.anti_scam_treasury_pda.key(),
             standard_collection: default_collection,
             scammed_collection: default_collection,
-            mint_fee_basis_points,
+            mint_fee_basis_points: 0,
             treasury_fee_percent,
             antiscam_fee_percent,
             minimum_payment,                                     // New field
             paused: false,                                       // Initialize as not paused
             total_minted_standard: 0,                            // No NFTs minted yet
             total_minted_scammed: 0,                             // No NFTs minted yet
-            version: 1,                                          // Initial version
+            version: CONFIG_VERSION,                             // Current layout
             standard_collection_has_master_edition: true,        // Initialize as true
             standard_collection_max_supply: standard_max_supply, // Limit to 100 editions
             scammed_collection_has_master_edition: true,         // Initialize as true
             scammed_collection_max_supply: scammed_max_supply,   // Limit to 50 editions
+            total_withdrawn_treasury: 0,                         // Nothing withdrawn yet
+            total_withdrawn_antiscam: 0,                         // Nothing withdrawn yet
+            disbursement_council: Pubkey::default(),             // No council until one is set up
+            fee_remainder_side: FeeRemainderSide::AntiScam,      // Rounding dust goes to anti-scam work
+            fee_schedule: Pubkey::default(),                     // Two-treasury split until a schedule exists
+            standard_min_report_stake: 0,                        // Staking is optional
+            victim_royalty_basis_points: 0,                      // Collection royalties only
+            victim_royalty_percent: 0,                           // Collection royalties only
         });
 
         Ok(())
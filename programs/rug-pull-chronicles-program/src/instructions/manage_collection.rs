@@ -0,0 +1,260 @@
+use crate::error::RuggedError;
+use crate::instructions::add_collection_plugin::{royalties_plugin, CreatorInput, RoyaltyRuleSet};
+use crate::state::collection::CollectionRecord;
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use mpl_core::{
+    instructions::{
+        AddCollectionPluginV1CpiBuilder, RemoveCollectionPluginV1CpiBuilder,
+        UpdateCollectionPluginV1CpiBuilder, UpdateCollectionV1CpiBuilder,
+    },
+    types::{Attribute, Attributes, MasterEdition, Plugin, PluginType},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttributeInput {
+    pub key: String,
+    pub value: String,
+}
+
+/// Collection plugins the admin can manage, with their new contents
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum CollectionPluginArgs {
+    Royalties {
+        basis_points: u16,
+        creators: Vec<CreatorInput>,
+        rule_set: RoyaltyRuleSet,
+    },
+    Attributes {
+        attributes: Vec<AttributeInput>,
+    },
+    MasterEdition {
+        max_supply: Option<u32>,
+        name: Option<String>,
+        uri: Option<String>,
+    },
+}
+
+/// Collection plugins the admin can remove
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub enum CollectionPluginKind {
+    Royalties,
+    Attributes,
+    MasterEdition,
+}
+
+impl CollectionPluginArgs {
+    fn into_plugin(self) -> Result<Plugin> {
+        Ok(match self {
+            Self::Royalties {
+                basis_points,
+                creators,
+                rule_set,
+            } => royalties_plugin(basis_points, creators, rule_set)?,
+            Self::Attributes { attributes } => Plugin::Attributes(Attributes {
+                attribute_list: attributes
+                    .into_iter()
+                    .map(|a| Attribute {
+                        key: a.key,
+                        value: a.value,
+                    })
+                    .collect(),
+            }),
+            Self::MasterEdition {
+                max_supply,
+                name,
+                uri,
+            } => Plugin::MasterEdition(MasterEdition {
+                max_supply,
+                name,
+                uri,
+            }),
+        })
+    }
+}
+
+impl From<CollectionPluginKind> for PluginType {
+    fn from(kind: CollectionPluginKind) -> Self {
+        match kind {
+            CollectionPluginKind::Royalties => PluginType::Royalties,
+            CollectionPluginKind::Attributes => PluginType::Attributes,
+            CollectionPluginKind::MasterEdition => PluginType::MasterEdition,
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct ManageCollection<'info> {
+    /// The admin managing the collection; pays for any account growth
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account, kept in sync with MasterEdition changes
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// A collection bound to a config slot or to a registry entry
+    /// CHECK: Checked against the config slots and `collection_record` in the handler
+    #[account(mut)]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Registry entry of `collection`, required unless it is bound to a config slot
+    #[account(
+        mut,
+        seeds = [b"collection_record", collection.key().as_ref()],
+        bump = collection_record.bump,
+    )]
+    pub collection_record: Option<Account<'info, CollectionRecord>>,
+
+    /// The program's update authority PDA
+    /// CHECK: This is a PDA that only this program can sign for
+    #[account(
+        seeds = [b"upd_auth"],
+        bump = config.update_authority_bump,
+    )]
+    pub update_authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: This is the ID of the Metaplex Core program
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ManageCollection<'info> {
+    pub fn update_collection_metadata(
+        &self,
+        name: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        self.check_registered()?;
+
+        let mpl_program_account = self.mpl_core_program.to_account_info();
+        let mut builder = UpdateCollectionV1CpiBuilder::new(&mpl_program_account);
+        let collection_account = self.collection.to_account_info();
+        let payer_account = self.admin.to_account_info();
+        let update_authority_account = self.update_authority_pda.to_account_info();
+        let system_program_account = self.system_program.to_account_info();
+        builder
+            .collection(&collection_account)
+            .payer(&payer_account)
+            .authority(Some(&update_authority_account))
+            .system_program(&system_program_account);
+        if let Some(name) = name {
+            builder.new_name(name);
+        }
+        if let Some(uri) = uri {
+            builder.new_uri(uri);
+        }
+        builder.invoke_signed(&[&[b"upd_auth", &[self.config.update_authority_bump]]])?;
+
+        Ok(())
+    }
+
+    pub fn add_collection_plugin(&mut self, plugin: CollectionPluginArgs) -> Result<()> {
+        self.check_registered()?;
+        self.sync_max_supply(&plugin)?;
+        let plugin = plugin.into_plugin()?;
+
+        AddCollectionPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .collection(&self.collection.to_account_info())
+            .authority(Some(&self.update_authority_pda.to_account_info()))
+            .payer(&self.admin.to_account_info())
+            .system_program(&self.system_program.to_account_info())
+            .plugin(plugin)
+            .invoke_signed(&[&[b"upd_auth", &[self.config.update_authority_bump]]])?;
+
+        Ok(())
+    }
+
+    pub fn update_collection_plugin(&mut self, plugin: CollectionPluginArgs) -> Result<()> {
+        self.check_registered()?;
+        self.sync_max_supply(&plugin)?;
+        let plugin = plugin.into_plugin()?;
+
+        UpdateCollectionPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .collection(&self.collection.to_account_info())
+            .authority(Some(&self.update_authority_pda.to_account_info()))
+            .payer(&self.admin.to_account_info())
+            .system_program(&self.system_program.to_account_info())
+            .plugin(plugin)
+            .invoke_signed(&[&[b"upd_auth", &[self.config.update_authority_bump]]])?;
+
+        Ok(())
+    }
+
+    pub fn remove_collection_plugin(&mut self, plugin_kind: CollectionPluginKind) -> Result<()> {
+        self.check_registered()?;
+        if let CollectionPluginKind::MasterEdition = plugin_kind {
+            self.set_max_supply(None)?;
+        }
+
+        RemoveCollectionPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
+            .collection(&self.collection.to_account_info())
+            .authority(Some(&self.update_authority_pda.to_account_info()))
+            .payer(&self.admin.to_account_info())
+            .system_program(&self.system_program.to_account_info())
+            .plugin_type(plugin_kind.into())
+            .invoke_signed(&[&[b"upd_auth", &[self.config.update_authority_bump]]])?;
+
+        Ok(())
+    }
+
+    /// Only collections bound to a config slot or the registry can be managed
+    fn check_registered(&self) -> Result<()> {
+        let collection = self.collection.key();
+        require!(
+            collection == self.config.standard_collection
+                || collection == self.config.scammed_collection
+                || self.collection_record.is_some(),
+            RuggedError::InvalidCollection
+        );
+
+        Ok(())
+    }
+
+    fn sync_max_supply(&mut self, plugin: &CollectionPluginArgs) -> Result<()> {
+        if let CollectionPluginArgs::MasterEdition { max_supply, .. } = plugin {
+            self.set_max_supply(*max_supply)?;
+        }
+
+        Ok(())
+    }
+
+    /// Mirrors a MasterEdition change into the config slot or registry entry the
+    /// collection is bound to. The limit can't drop below what was already minted.
+    fn set_max_supply(&mut self, max_supply: Option<u32>) -> Result<()> {
+        let fits = |minted: u64| max_supply.is_none_or(|max_supply| max_supply as u64 >= minted);
+
+        let collection = self.collection.key();
+        if collection == self.config.standard_collection {
+            require!(
+                fits(self.config.total_minted_standard),
+                RuggedError::InvalidCollection
+            );
+            self.config.standard_collection_has_master_edition = max_supply.is_some();
+            self.config.standard_collection_max_supply = max_supply;
+        } else if collection == self.config.scammed_collection {
+            require!(
+                fits(self.config.total_minted_scammed),
+                RuggedError::InvalidCollection
+            );
+            self.config.scammed_collection_has_master_edition = max_supply.is_some();
+            self.config.scammed_collection_max_supply = max_supply;
+        }
+
+        if let Some(record) = self.collection_record.as_mut() {
+            require!(
+                record.fits_max_supply(max_supply),
+                RuggedError::InvalidCollection
+            );
+            record.max_supply = max_supply;
+        }
+
+        Ok(())
+    }
+}
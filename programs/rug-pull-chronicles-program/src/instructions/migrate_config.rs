@@ -0,0 +1,72 @@
+use crate::error::RuggedError;
+use crate::events::ConfigMigrated;
+use crate::state::config::{Config, CONFIG_VERSION};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct MigrateConfig<'info> {
+    /// The admin; pays for the extra space
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// The config account, possibly in an older, shorter layout
+    /// CHECK: Older layouts don't deserialize as `Config`; the owner, discriminator
+    /// and admin are checked in the handler
+    #[account(
+        mut,
+        seeds = [b"config", seed.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    /// Grows the config to the current layout and fills in the fields it was missing
+    pub fn migrate_config(&mut self) -> Result<()> {
+        let account = self.config.to_account_info();
+        require_keys_eq!(
+            *account.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+
+        // Fields appended since the account was created read as zeroes
+        let mut data = account.try_borrow_data()?.to_vec();
+        data.resize(data.len().max(Config::INIT_SPACE), 0);
+        let mut config = Config::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(self.admin.key(), config.admin, RuggedError::Unauthorized);
+        let from_version = config.version;
+        config.migrate();
+
+        if account.data_len() < Config::INIT_SPACE {
+            let rent = Rent::get()?.minimum_balance(Config::INIT_SPACE);
+            let top_up = rent.saturating_sub(account.lamports());
+            if top_up > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        self.system_program.to_account_info(),
+                        Transfer {
+                            from: self.admin.to_account_info(),
+                            to: account.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            account.realloc(Config::INIT_SPACE, true)?;
+        }
+        config.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+
+        emit!(ConfigMigrated {
+            config: account.key(),
+            from_version,
+            to_version: CONFIG_VERSION,
+        });
+
+        Ok(())
+    }
+}
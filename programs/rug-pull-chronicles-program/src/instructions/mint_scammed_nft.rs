@@ -1,14 +1,23 @@
 #![allow(unexpected_cfgs)]
+use crate::events::{SafeDestinationUsed, VictimAttestationUsed};
 use crate::state::*;
-use crate::utils::fees::calculate_mint_fees;
+use crate::utils::ed25519::{has_ed25519_signature, signed_message};
+use crate::utils::fees::{split_beneficiary_accounts, MintPayment, TokenFeeAccounts};
+use crate::utils::incident::IncidentAccounts;
+use crate::utils::moderation::{pending_report_plugins, pending_status_attribute};
+use crate::utils::pda::create_pda_account;
+use crate::utils::royalties::victim_royalties_plugin;
+use crate::utils::scammer_registry::record_scammer_reports;
+use crate::utils::time::year_from_unix_timestamp;
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use mpl_core::{
     instructions::{AddPluginV1CpiBuilder, CreateV2CpiBuilder},
     types::{Attribute, Attributes, Plugin},
 };
 
 #[derive(Accounts)]
-#[instruction(name: String, uri: String, scam_details: String)]
+#[instruction(name: String, uri: String, scam_details: String, report: ScamReportArgs, payment_amount: u64)]
 pub struct MintScammedNft<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -57,6 +66,53 @@ pub struct MintScammedNft<'info> {
     )]
     pub mint_tracker: Account<'info, MintTracker>,
 
+    /// Structured record of the documented scam. One `ScammerProfile` PDA per scammer
+    /// address it names goes in `remaining_accounts`, after any fee beneficiaries.
+    #[account(
+        init,
+        payer = user,
+        space = ScamReport::INIT_SPACE,
+        seeds = [b"scam_report", rugged_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// Evidence attached to the report; `add_evidence` can append more later
+    #[account(
+        init,
+        payer = user,
+        space = Evidence::space_for(&report.evidence),
+        seeds = [b"evidence", rugged_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub evidence: Box<Account<'info, Evidence>>,
+
+    /// Platform categories and attack types the report's ids refer to
+    #[account(
+        seeds = [b"taxonomy", config.key().as_ref()],
+        bump = taxonomy.bump,
+    )]
+    pub taxonomy: Box<Account<'info, Taxonomy>>,
+
+    /// Incident the report documents, shared with other reports of the same scam.
+    /// Created by the first report.
+    /// CHECK: Created or updated in the handler
+    #[account(
+        mut,
+        seeds = [b"incident", report.chain_id.to_le_bytes().as_ref(), report.scam_contract.as_ref()],
+        bump
+    )]
+    pub incident: UncheckedAccount<'info>,
+
+    /// Marks that `user` documented the incident. Must not exist yet.
+    /// CHECK: Created in the handler, which rejects existing accounts
+    #[account(
+        mut,
+        seeds = [b"incident_reporter", incident.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub incident_reporter: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
     /// CHECK: This is the ID of the Metaplex Core program
     #[account(address = mpl_core::ID)]
@@ -65,14 +121,90 @@ pub struct MintScammedNft<'info> {
     /// Config account to store the collection address
     #[account(mut)]
     pub config: Account<'info, Config>,
+
+    /// Optional SPL mint to pay the fee with instead of SOL.
+    /// When set, all of the token accounts below must be provided as well.
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Accepted-mint record for `payment_mint`
+    pub accepted_mint: Option<Box<Account<'info, AcceptedMint>>>,
+
+    /// The user's token account the fee is paid from
+    #[account(mut)]
+    pub payer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Treasury PDA's associated token account for `payment_mint`
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Anti-scam treasury PDA's associated token account for `payment_mint`
+    #[account(mut)]
+    pub antiscam_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token or Token-2022 program owning `payment_mint`
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// Fee schedule, required once `config.fee_schedule` is set.
+    /// Its beneficiaries' accounts are passed first in `remaining_accounts`, in schedule order.
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
+
+    /// The minting wallet's victim verification record.
+    /// Required when the program is built with the `scam_verification` feature.
+    #[account(
+        seeds = [crate::constants::VERIFICATION_SEED, user.key().as_ref()],
+        bump = victim_verification.bump,
+    )]
+    pub victim_verification: Option<Box<Account<'info, VictimVerification>>>,
+
+    /// Verifier that issued `victim_verification`, checked in the handler.
+    /// Required along with it when the program is built with `scam_verification`.
+    pub victim_verifier: Option<Box<Account<'info, Verifier>>>,
+
+    /// Verifier whose key signed the `attestation` argument, if one is given
+    #[account(
+        mut,
+        seeds = [b"verifier", attestation_verifier.authority.as_ref()],
+        bump = attestation_verifier.bump,
+    )]
+    pub attestation_verifier: Option<Box<Account<'info, Verifier>>>,
+
+    /// Nonce PDA for the attestation, created by the handler to block replays
+    /// CHECK: Address is derived from the attestation and checked in the handler
+    #[account(mut)]
+    pub attestation_nonce: Option<UncheckedAccount<'info>>,
+
+    /// Instructions sysvar, used to find Ed25519 signature checks for the
+    /// `attestation` and `safe_destination` arguments
+    /// CHECK: Address is checked against the sysvar id
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Safe wallet that will own the asset, required with the `safe_destination` argument
+    /// CHECK: Checked against the authorization in the handler
+    pub destination: Option<UncheckedAccount<'info>>,
+
+    /// The compromised wallet, when it co-signs the `safe_destination` authorization
+    /// instead of pre-signing it
+    pub compromised_wallet: Option<Signer<'info>>,
+
+    /// Nonce PDA for a pre-signed `safe_destination`, created by the handler to block replays
+    /// CHECK: Address is derived from the authorization and checked in the handler
+    #[account(mut)]
+    pub destination_nonce: Option<UncheckedAccount<'info>>,
 }
 
 impl<'info> MintScammedNft<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn mint_core_asset(
         &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
         name: String,
         uri: String,
         scam_details: String,
+        mut report: ScamReportArgs,
+        payment_amount: u64,
+        attestation: Option<VictimAttestation>,
+        safe_destination: Option<SafeDestination>,
+        bumps: &MintScammedNftBumps,
     ) -> Result<()> {
         // Check if the program is paused
         require!(
@@ -80,6 +212,65 @@ impl<'info> MintScammedNft<'info> {
             crate::error::RuggedError::ProgramPaused
         );
 
+        // Reject scam details that can't be stored as a well-formed report
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            report.is_valid(year_from_unix_timestamp(timestamp)),
+            crate::error::RuggedError::InvalidTraits
+        );
+        let report_attributes = report.attributes(&self.taxonomy)?;
+        let evidence = std::mem::take(&mut report.evidence);
+        require!(
+            evidence.len() <= MAX_EVIDENCE_ENTRIES
+                && evidence.iter().all(EvidenceEntryArgs::is_valid),
+            crate::error::RuggedError::InvalidEvidence
+        );
+
+        // Link the report to its incident; each wallet documents an incident once
+        IncidentAccounts {
+            payer: &self.user.to_account_info(),
+            system_program: &self.system_program.to_account_info(),
+            incident: &self.incident.to_account_info(),
+            incident_bump: bumps.incident,
+            incident_reporter: &self.incident_reporter.to_account_info(),
+            incident_reporter_bump: bumps.incident_reporter,
+        }
+        .record(
+            &report,
+            self.user.key(),
+            self.rugged_nft_mint.key(),
+            timestamp,
+        )?;
+
+        // The asset goes to the signer, or to a safe wallet the compromised wallet authorized
+        let owner_account = match &safe_destination {
+            Some(safe_destination) => {
+                // The drained wallet must be the minting wallet, or the one the
+                // attestation vouches for; the attestation is checked below
+                match &attestation {
+                    Some(attestation) => require_keys_eq!(
+                        attestation.compromised_address,
+                        safe_destination.compromised,
+                        crate::error::RuggedError::InvalidAttestation
+                    ),
+                    None => require_keys_eq!(
+                        safe_destination.compromised,
+                        self.user.key(),
+                        crate::error::RuggedError::DestinationNotAuthorized
+                    ),
+                }
+                self.authorize_safe_destination(safe_destination)?
+            }
+            None => self.user.to_account_info(),
+        };
+
+        // Only verified victims can mint into the scammed collection
+        let verified = self.verify_victim(attestation)?;
+        require!(
+            verified || !cfg!(feature = "scam_verification"),
+            crate::error::RuggedError::RuggedUserNotVerified
+        );
+
         // Check if we've reached the max supply limit for this collection
         if self.config.scammed_collection_has_master_edition {
             if let Some(max_supply) = self.config.scammed_collection_max_supply {
@@ -100,37 +291,34 @@ impl<'info> MintScammedNft<'info> {
             }
         }
 
-        // Calculate the fees first
-        let (treasury_amount, antiscam_amount) = calculate_mint_fees(&self.config)?;
-
-        // Transfer to main treasury
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: self.user.to_account_info(),
-                    to: self.treasury.to_account_info(),
-                },
-            ),
-            treasury_amount,
-        )?;
-
-        // Transfer to anti-scam treasury
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: self.user.to_account_info(),
-                    to: self.antiscam_treasury.to_account_info(),
-                },
-            ),
-            antiscam_amount,
+        // Collect the payment first, in SOL or in the accepted payment token
+        let token_fee = TokenFeeAccounts::from_optional(
+            self.payment_mint.as_deref(),
+            self.accepted_mint.as_deref(),
+            self.payer_token_account.as_deref(),
+            self.treasury_token_account.as_deref(),
+            self.antiscam_token_account.as_deref(),
+            self.token_program.as_ref(),
         )?;
+        let fee_schedule = self.fee_schedule.as_deref();
+        let (beneficiary_accounts, profile_accounts) =
+            split_beneficiary_accounts(&self.config, fee_schedule, remaining_accounts)?;
+        MintPayment {
+            config: &self.config,
+            payer: &self.user,
+            treasury: &self.treasury.to_account_info(),
+            antiscam_treasury: &self.antiscam_treasury.to_account_info(),
+            system_program: &self.system_program,
+            token_fee,
+            fee_schedule,
+            beneficiary_accounts,
+            fee_overrides: CollectionFeeOverrides::default(),
+        }
+        .collect(self.rugged_nft_mint.key(), payment_amount)?;
 
         // Get the account infos first
         let collection_account = &self.scammed_collection;
         let payer_account = &self.user.to_account_info();
-        let owner_account = &self.user.to_account_info();
         let system_program_account = &self.system_program.to_account_info();
         let asset_account = &self.rugged_nft_mint.to_account_info();
         let mpl_program_account = &self.mpl_core_program.to_account_info();
@@ -142,40 +330,51 @@ impl<'info> MintScammedNft<'info> {
         // Create the asset using V2 builder with collection's update authority signing
         // When we specify a collection, the collection becomes the update authority
         // and we need the collection's update authority to approve this operation
+        // The asset starts frozen until a moderator approves the report, and can pay
+        // part of its secondary royalties to the victim's wallet
+        let mut plugins = pending_report_plugins(self.update_authority_pda.key());
+        plugins.extend(victim_royalties_plugin(
+            &self.config,
+            collection_account,
+            owner_account.key(),
+        ));
         CreateV2CpiBuilder::new(mpl_program_account)
             .asset(asset_account)
             .collection(Some(collection_account))
             .authority(Some(update_authority_account))
             .payer(payer_account)
-            .owner(Some(owner_account))
+            .owner(Some(&owner_account))
             .system_program(system_program_account)
             .name(name)
             .uri(uri)
+            .plugins(plugins)
             .invoke_signed(&[&[b"upd_auth", &[bump]]])?;
 
         // Add the attributes plugin with scam details and minting metadata
-        let timestamp = Clock::get()?.unix_timestamp;
-        let attributes = Attributes {
-            attribute_list: vec![
-                // Scam details
-                Attribute {
-                    key: "id".to_string(),
-                    value: (self.config.total_minted_scammed + 1).to_string(),
-                },
-                Attribute {
-                    key: "scam_details".to_string(),
-                    value: scam_details,
-                },
-                Attribute {
-                    key: "minted_by".to_string(),
-                    value: self.user.key().to_string(),
-                },
-                Attribute {
-                    key: "minted_at".to_string(),
-                    value: timestamp.to_string(),
-                },
-            ],
-        };
+        let mut attribute_list = vec![
+            Attribute {
+                key: "id".to_string(),
+                value: (self.config.total_minted_scammed + 1).to_string(),
+            },
+            Attribute {
+                key: "scam_details".to_string(),
+                value: scam_details,
+            },
+        ];
+        // Structured scam details, derived from the typed report
+        attribute_list.extend(report_attributes);
+        attribute_list.extend([
+            Attribute {
+                key: "minted_by".to_string(),
+                value: self.user.key().to_string(),
+            },
+            Attribute {
+                key: "minted_at".to_string(),
+                value: timestamp.to_string(),
+            },
+            pending_status_attribute(),
+        ]);
+        let attributes = Attributes { attribute_list };
 
         // Add the plugin with all attributes
         AddPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
@@ -187,6 +386,46 @@ impl<'info> MintScammedNft<'info> {
             .plugin(Plugin::Attributes(attributes))
             .invoke_signed(&[&[b"upd_auth", &[bump]]])?;
 
+        if let Some(safe_destination) = safe_destination {
+            emit!(SafeDestinationUsed {
+                asset: self.rugged_nft_mint.key(),
+                compromised: safe_destination.compromised,
+                destination: safe_destination.destination,
+            });
+        }
+
+        // Link the report to the profile of every scammer address it names.
+        // The profiles follow the fee beneficiaries in `remaining_accounts`.
+        record_scammer_reports(
+            &self.user.to_account_info(),
+            &self.system_program.to_account_info(),
+            profile_accounts,
+            &report,
+            self.rugged_nft_mint.key(),
+            verified,
+            timestamp,
+        )?;
+
+        // Attach the report's initial evidence
+        let mut evidence_account = Evidence {
+            asset: self.rugged_nft_mint.key(),
+            entries: Vec::with_capacity(evidence.len()),
+            bump: bumps.evidence,
+        };
+        evidence_account.append(evidence, self.user.key(), timestamp);
+        self.evidence.set_inner(evidence_account);
+
+        // Store the typed report alongside the asset
+        self.scam_report.set_inner(ScamReport::new(
+            self.rugged_nft_mint.key(),
+            self.user.key(),
+            report,
+            self.incident.key(),
+            timestamp,
+            verified,
+            bumps.scam_report,
+        ));
+
         // Set the mint tracker flag to true to prevent duplicate mints
         self.mint_tracker.is_minted = true;
 
@@ -199,4 +438,194 @@ impl<'info> MintScammedNft<'info> {
 
         Ok(())
     }
+
+    /// Checks the minting wallet's victim verification, either an on-chain
+    /// `VictimVerification` record or a signed attestation, and returns whether it
+    /// holds. A given attestation must be valid and is consumed either way.
+    fn verify_victim(&mut self, attestation: Option<VictimAttestation>) -> Result<bool> {
+        let has_record = match (&self.victim_verification, &self.victim_verifier) {
+            (Some(record), Some(verifier)) => record.is_honoured_by(&verifier.key(), verifier),
+            _ => false,
+        };
+
+        let Some(attestation) = attestation else {
+            return Ok(has_record);
+        };
+        let (Some(verifier), Some(nonce_account), Some(instructions_sysvar)) = (
+            self.attestation_verifier.as_mut(),
+            self.attestation_nonce.as_ref(),
+            self.instructions_sysvar.as_ref(),
+        ) else {
+            return err!(crate::error::RuggedError::InvalidAttestation);
+        };
+
+        require_keys_eq!(
+            attestation.victim,
+            self.user.key(),
+            crate::error::RuggedError::InvalidAttestation
+        );
+        require!(
+            verifier.can_verify(VERIFIER_SCOPE_SCAMMED),
+            crate::error::RuggedError::InvalidVerifier
+        );
+        require!(
+            Clock::get()?.unix_timestamp < attestation.expires_at,
+            crate::error::RuggedError::AttestationExpired
+        );
+        require!(
+            has_ed25519_signature(
+                instructions_sysvar,
+                &verifier.authority,
+                &signed_message(VICTIM_ATTESTATION_TAG, &self.config.key(), &attestation)?,
+            )?,
+            crate::error::RuggedError::InvalidAttestation
+        );
+
+        // Burn the nonce; a second mint with the same attestation finds it already in use
+        let nonce_bytes = attestation.nonce.to_le_bytes();
+        let (nonce_address, nonce_bump) = Pubkey::find_program_address(
+            &[
+                b"attestation_nonce",
+                verifier.authority.as_ref(),
+                &nonce_bytes,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            nonce_account.key(),
+            nonce_address,
+            crate::error::RuggedError::InvalidAttestation
+        );
+        require_keys_eq!(
+            *nonce_account.owner,
+            System::id(),
+            crate::error::RuggedError::AttestationReplayed
+        );
+        create_pda_account(
+            &self.user.to_account_info(),
+            nonce_account,
+            &self.system_program.to_account_info(),
+            AttestationNonce::INIT_SPACE,
+            &[
+                b"attestation_nonce",
+                verifier.authority.as_ref(),
+                &nonce_bytes,
+                &[nonce_bump],
+            ],
+        )?;
+        AttestationNonce { is_used: true }
+            .try_serialize(&mut &mut nonce_account.try_borrow_mut_data()?[..])?;
+
+        verifier.verifications_issued = verifier
+            .verifications_issued
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+
+        emit!(VictimAttestationUsed {
+            verifier: verifier.key(),
+            victim: attestation.victim,
+            compromised_address: attestation.compromised_address,
+            nonce: attestation.nonce,
+        });
+
+        Ok(true)
+    }
+
+    /// Checks a safe-destination authorization and returns the destination account.
+    /// The compromised wallet must have co-signed the mint or signed the authorization
+    /// through the Ed25519 program, and the authorization must be short-lived.
+    /// Pre-signed authorizations burn their nonce.
+    fn authorize_safe_destination(
+        &self,
+        safe_destination: &SafeDestination,
+    ) -> Result<AccountInfo<'info>> {
+        require_keys_neq!(
+            safe_destination.destination,
+            safe_destination.compromised,
+            crate::error::RuggedError::InvalidDestination
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < safe_destination.expires_at
+                && safe_destination.expires_at <= now.saturating_add(MAX_SAFE_DESTINATION_TTL),
+            crate::error::RuggedError::DestinationAuthorizationExpired
+        );
+
+        let co_signed = safe_destination.compromised == self.user.key()
+            || self
+                .compromised_wallet
+                .as_ref()
+                .is_some_and(|wallet| wallet.key() == safe_destination.compromised);
+        let pre_signed = match &self.instructions_sysvar {
+            Some(instructions_sysvar) if !co_signed => has_ed25519_signature(
+                instructions_sysvar,
+                &safe_destination.compromised,
+                &signed_message(SAFE_DESTINATION_TAG, &self.config.key(), safe_destination)?,
+            )?,
+            _ => false,
+        };
+        require!(
+            co_signed || pre_signed,
+            crate::error::RuggedError::DestinationNotAuthorized
+        );
+        if pre_signed {
+            self.burn_destination_nonce(safe_destination)?;
+        }
+
+        let destination = self
+            .destination
+            .as_ref()
+            .ok_or(crate::error::RuggedError::DestinationNotAuthorized)?;
+        require_keys_eq!(
+            destination.key(),
+            safe_destination.destination,
+            crate::error::RuggedError::DestinationNotAuthorized
+        );
+
+        Ok(destination.to_account_info())
+    }
+
+    /// Creates the nonce PDA of a pre-signed safe-destination authorization; a second
+    /// mint with the same authorization finds it already in use
+    fn burn_destination_nonce(&self, safe_destination: &SafeDestination) -> Result<()> {
+        let nonce_account = self
+            .destination_nonce
+            .as_ref()
+            .ok_or(crate::error::RuggedError::DestinationNotAuthorized)?;
+
+        let nonce_bytes = safe_destination.nonce.to_le_bytes();
+        let (nonce_address, nonce_bump) = Pubkey::find_program_address(
+            &[
+                b"destination_nonce",
+                safe_destination.compromised.as_ref(),
+                &nonce_bytes,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            nonce_account.key(),
+            nonce_address,
+            crate::error::RuggedError::DestinationNotAuthorized
+        );
+        require_keys_eq!(
+            *nonce_account.owner,
+            System::id(),
+            crate::error::RuggedError::DestinationAuthorizationReplayed
+        );
+        create_pda_account(
+            &self.user.to_account_info(),
+            nonce_account,
+            &self.system_program.to_account_info(),
+            DestinationNonce::INIT_SPACE,
+            &[
+                b"destination_nonce",
+                safe_destination.compromised.as_ref(),
+                &nonce_bytes,
+                &[nonce_bump],
+            ],
+        )?;
+        DestinationNonce { is_used: true }
+            .try_serialize(&mut &mut nonce_account.try_borrow_mut_data()?[..])
+    }
 }
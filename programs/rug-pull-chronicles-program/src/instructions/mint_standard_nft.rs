@@ -1,14 +1,20 @@
 #![allow(unexpected_cfgs)]
 use crate::state::*;
-use crate::utils::fees::calculate_mint_fees;
+use crate::utils::fees::{split_beneficiary_accounts, MintPayment, TokenFeeAccounts};
+use crate::utils::incident::IncidentAccounts;
+use crate::utils::moderation::{pending_report_plugins, pending_status_attribute};
+use crate::utils::report_stake::StakeAccounts;
+use crate::utils::scammer_registry::record_scammer_reports;
+use crate::utils::time::year_from_unix_timestamp;
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use mpl_core::{
     instructions::{AddPluginV1CpiBuilder, CreateV2CpiBuilder},
     types::{Attribute, Attributes, Plugin},
 };
 
 #[derive(Accounts)]
-#[instruction(name: String, uri: String, scam_year: String, usd_amount_stolen: String, platform_category: String, type_of_attack: String)]
+#[instruction(name: String, uri: String, report: ScamReportArgs, payment_amount: u64, stake_amount: u64)]
 pub struct MintStandardNft<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -57,6 +63,62 @@ pub struct MintStandardNft<'info> {
     )]
     pub mint_tracker: Account<'info, MintTracker>,
 
+    /// Escrow for the reporter's stake, created only when `stake_amount` is non-zero
+    /// CHECK: Created in the handler
+    #[account(
+        mut,
+        seeds = [b"report_stake", rugged_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub report_stake: UncheckedAccount<'info>,
+
+    /// Structured record of the documented scam. One `ScammerProfile` PDA per scammer
+    /// address it names goes in `remaining_accounts`, after any fee beneficiaries.
+    #[account(
+        init,
+        payer = user,
+        space = ScamReport::INIT_SPACE,
+        seeds = [b"scam_report", rugged_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// Evidence attached to the report; `add_evidence` can append more later
+    #[account(
+        init,
+        payer = user,
+        space = Evidence::space_for(&report.evidence),
+        seeds = [b"evidence", rugged_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub evidence: Box<Account<'info, Evidence>>,
+
+    /// Platform categories and attack types the report's ids refer to
+    #[account(
+        seeds = [b"taxonomy", config.key().as_ref()],
+        bump = taxonomy.bump,
+    )]
+    pub taxonomy: Box<Account<'info, Taxonomy>>,
+
+    /// Incident the report documents, shared with other reports of the same scam.
+    /// Created by the first report.
+    /// CHECK: Created or updated in the handler
+    #[account(
+        mut,
+        seeds = [b"incident", report.chain_id.to_le_bytes().as_ref(), report.scam_contract.as_ref()],
+        bump
+    )]
+    pub incident: UncheckedAccount<'info>,
+
+    /// Marks that `user` documented the incident. Must not exist yet.
+    /// CHECK: Created in the handler, which rejects existing accounts
+    #[account(
+        mut,
+        seeds = [b"incident_reporter", incident.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub incident_reporter: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
     /// CHECK: This is the ID of the Metaplex Core program
     #[account(address = mpl_core::ID)]
@@ -65,17 +127,44 @@ pub struct MintStandardNft<'info> {
     /// Config account to store the collection address
     #[account(mut)]
     pub config: Account<'info, Config>,
+
+    /// Optional SPL mint to pay the fee with instead of SOL.
+    /// When set, all of the token accounts below must be provided as well.
+    pub payment_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    /// Accepted-mint record for `payment_mint`
+    pub accepted_mint: Option<Box<Account<'info, AcceptedMint>>>,
+
+    /// The user's token account the fee is paid from
+    #[account(mut)]
+    pub payer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Treasury PDA's associated token account for `payment_mint`
+    #[account(mut)]
+    pub treasury_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Anti-scam treasury PDA's associated token account for `payment_mint`
+    #[account(mut)]
+    pub antiscam_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Token or Token-2022 program owning `payment_mint`
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    /// Fee schedule, required once `config.fee_schedule` is set.
+    /// Its beneficiaries' accounts are passed first in `remaining_accounts`, in schedule order.
+    pub fee_schedule: Option<Box<Account<'info, FeeSchedule>>>,
 }
 
 impl<'info> MintStandardNft<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn mint_core_asset(
         &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
         name: String,
         uri: String,
-        scam_year: String,
-        usd_amount_stolen: String,
-        platform_category: String,
-        type_of_attack: String,
+        mut report: ScamReportArgs,
+        payment_amount: u64,
+        stake_amount: u64,
+        bumps: &MintStandardNftBumps,
     ) -> Result<()> {
         // Check if the program is paused
         require!(
@@ -83,6 +172,36 @@ impl<'info> MintStandardNft<'info> {
             crate::error::RuggedError::ProgramPaused
         );
 
+        // Reject scam details that can't be stored as a well-formed report
+        let timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            report.is_valid(year_from_unix_timestamp(timestamp)),
+            crate::error::RuggedError::InvalidTraits
+        );
+        let report_attributes = report.attributes(&self.taxonomy)?;
+        let evidence = std::mem::take(&mut report.evidence);
+        require!(
+            evidence.len() <= MAX_EVIDENCE_ENTRIES
+                && evidence.iter().all(EvidenceEntryArgs::is_valid),
+            crate::error::RuggedError::InvalidEvidence
+        );
+
+        // Link the report to its incident; each wallet documents an incident once
+        IncidentAccounts {
+            payer: &self.user.to_account_info(),
+            system_program: &self.system_program.to_account_info(),
+            incident: &self.incident.to_account_info(),
+            incident_bump: bumps.incident,
+            incident_reporter: &self.incident_reporter.to_account_info(),
+            incident_reporter_bump: bumps.incident_reporter,
+        }
+        .record(
+            &report,
+            self.user.key(),
+            self.rugged_nft_mint.key(),
+            timestamp,
+        )?;
+
         // Check if we've reached the max supply limit for this collection
         if self.config.standard_collection_has_master_edition {
             if let Some(max_supply) = self.config.standard_collection_max_supply {
@@ -103,32 +222,45 @@ impl<'info> MintStandardNft<'info> {
             }
         }
 
-        // Calculate the fees first
-        let (treasury_amount, antiscam_amount) = calculate_mint_fees(&self.config)?;
-
-        // Transfer to main treasury
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: self.user.to_account_info(),
-                    to: self.treasury.to_account_info(),
-                },
-            ),
-            treasury_amount,
+        // Collect the payment first, in SOL or in the accepted payment token
+        let token_fee = TokenFeeAccounts::from_optional(
+            self.payment_mint.as_deref(),
+            self.accepted_mint.as_deref(),
+            self.payer_token_account.as_deref(),
+            self.treasury_token_account.as_deref(),
+            self.antiscam_token_account.as_deref(),
+            self.token_program.as_ref(),
         )?;
+        let fee_schedule = self.fee_schedule.as_deref();
+        let (beneficiary_accounts, profile_accounts) =
+            split_beneficiary_accounts(&self.config, fee_schedule, remaining_accounts)?;
+        MintPayment {
+            config: &self.config,
+            payer: &self.user,
+            treasury: &self.treasury.to_account_info(),
+            antiscam_treasury: &self.antiscam_treasury.to_account_info(),
+            system_program: &self.system_program,
+            token_fee,
+            fee_schedule,
+            beneficiary_accounts,
+            fee_overrides: CollectionFeeOverrides::default(),
+        }
+        .collect(self.rugged_nft_mint.key(), payment_amount)?;
 
-        // Transfer to anti-scam treasury
-        anchor_lang::system_program::transfer(
-            CpiContext::new(
-                self.system_program.to_account_info(),
-                anchor_lang::system_program::Transfer {
-                    from: self.user.to_account_info(),
-                    to: self.antiscam_treasury.to_account_info(),
-                },
-            ),
-            antiscam_amount,
-        )?;
+        // Lock the reporter's stake, if any, until the report has stood long enough
+        require!(
+            stake_amount >= self.config.standard_min_report_stake,
+            crate::error::RuggedError::InvalidStake
+        );
+        if stake_amount > 0 {
+            StakeAccounts {
+                reporter: &self.user.to_account_info(),
+                system_program: &self.system_program.to_account_info(),
+                report_stake: &self.report_stake.to_account_info(),
+                report_stake_bump: bumps.report_stake,
+            }
+            .lock(self.rugged_nft_mint.key(), stake_amount, timestamp)?;
+        }
 
         // Get the account infos first
         let collection_account = &self.standard_collection;
@@ -145,6 +277,7 @@ impl<'info> MintStandardNft<'info> {
         // Create the asset using V2 builder with collection's update authority signing
         // When we specify a collection, the collection becomes the update authority
         // and we need the collection's update authority to approve this operation
+        // The asset starts frozen until a moderator approves the report
         CreateV2CpiBuilder::new(mpl_program_account)
             .asset(asset_account)
             .collection(Some(collection_account))
@@ -154,43 +287,28 @@ impl<'info> MintStandardNft<'info> {
             .system_program(system_program_account)
             .name(name)
             .uri(uri)
+            .plugins(pending_report_plugins(self.update_authority_pda.key()))
             .invoke_signed(&[&[b"upd_auth", &[bump]]])?;
 
         // Add the attributes plugin with scam details and minting metadata
-        let timestamp = Clock::get()?.unix_timestamp;
-        let attributes = Attributes {
-            attribute_list: vec![
-                // Scam details
-                Attribute {
-                    key: "id".to_string(),
-                    value: (self.config.total_minted_standard + 1).to_string(),
-                },
-                Attribute {
-                    key: "scam_year".to_string(),
-                    value: scam_year,
-                },
-                Attribute {
-                    key: "usd_amount_stolen".to_string(),
-                    value: usd_amount_stolen,
-                },
-                Attribute {
-                    key: "platform_category".to_string(),
-                    value: platform_category,
-                },
-                Attribute {
-                    key: "type_of_attack".to_string(),
-                    value: type_of_attack,
-                },
-                Attribute {
-                    key: "minted_by".to_string(),
-                    value: self.user.key().to_string(),
-                },
-                Attribute {
-                    key: "minted_at".to_string(),
-                    value: timestamp.to_string(),
-                },
-            ],
-        };
+        let mut attribute_list = vec![Attribute {
+            key: "id".to_string(),
+            value: (self.config.total_minted_standard + 1).to_string(),
+        }];
+        // Scam details, derived from the typed report
+        attribute_list.extend(report_attributes);
+        attribute_list.extend([
+            Attribute {
+                key: "minted_by".to_string(),
+                value: self.user.key().to_string(),
+            },
+            Attribute {
+                key: "minted_at".to_string(),
+                value: timestamp.to_string(),
+            },
+            pending_status_attribute(),
+        ]);
+        let attributes = Attributes { attribute_list };
 
         // Add the plugin with all attributes
         AddPluginV1CpiBuilder::new(&self.mpl_core_program.to_account_info())
@@ -202,6 +320,38 @@ impl<'info> MintStandardNft<'info> {
             .plugin(Plugin::Attributes(attributes))
             .invoke_signed(&[&[b"upd_auth", &[bump]]])?;
 
+        // Link the report to the profile of every scammer address it names.
+        // The profiles follow the fee beneficiaries in `remaining_accounts`.
+        record_scammer_reports(
+            &self.user.to_account_info(),
+            &self.system_program.to_account_info(),
+            profile_accounts,
+            &report,
+            self.rugged_nft_mint.key(),
+            false,
+            timestamp,
+        )?;
+
+        // Attach the report's initial evidence
+        let mut evidence_account = Evidence {
+            asset: self.rugged_nft_mint.key(),
+            entries: Vec::with_capacity(evidence.len()),
+            bump: bumps.evidence,
+        };
+        evidence_account.append(evidence, self.user.key(), timestamp);
+        self.evidence.set_inner(evidence_account);
+
+        // Store the typed report alongside the asset
+        self.scam_report.set_inner(ScamReport::new(
+            self.rugged_nft_mint.key(),
+            self.user.key(),
+            report,
+            self.incident.key(),
+            timestamp,
+            false,
+            bumps.scam_report,
+        ));
+
         // Set the mint tracker flag to true to prevent duplicate mints
         self.mint_tracker.is_minted = true;
 
@@ -18,3 +18,37 @@ pub mod freeze_asset;
 pub use freeze_asset::*;
 pub mod thaw_asset;
 pub use thaw_asset::*;
+pub mod withdraw_treasury;
+pub use withdraw_treasury::*;
+pub mod disbursement_council;
+pub use disbursement_council::*;
+pub mod disbursement;
+pub use disbursement::*;
+pub mod accepted_mint;
+pub use accepted_mint::*;
+pub mod fee_schedule;
+pub use fee_schedule::*;
+pub mod verify_victim;
+pub use verify_victim::*;
+pub mod verifier;
+pub use verifier::*;
+pub mod taxonomy;
+pub use taxonomy::*;
+pub mod check_address;
+pub use check_address::*;
+pub mod add_evidence;
+pub use add_evidence::*;
+pub mod moderation;
+pub use moderation::*;
+pub mod challenge;
+pub use challenge::*;
+pub mod report_stake;
+pub use report_stake::*;
+pub mod collection_registry;
+pub use collection_registry::*;
+pub mod mint_into_collection;
+pub use mint_into_collection::*;
+pub mod manage_collection;
+pub use manage_collection::*;
+pub mod migrate_config;
+pub use migrate_config::*;
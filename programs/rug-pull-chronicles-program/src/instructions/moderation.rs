@@ -0,0 +1,270 @@
+use crate::error::RuggedError;
+use crate::events::{ModeratorAdded, ModeratorRemoved, ReportModerated};
+use crate::state::*;
+use crate::utils::moderation::ModeratedAsset;
+use crate::utils::report_stake::slash_report_stake;
+use crate::utils::scammer_registry::update_scammer_profiles;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct AddModerator<'info> {
+    /// The admin appointing the moderator; pays for the moderator account
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The new moderator, keyed by its signing key
+    #[account(
+        init,
+        payer = admin,
+        space = Moderator::INIT_SPACE,
+        seeds = [b"moderator", authority.as_ref()],
+        bump
+    )]
+    pub moderator: Account<'info, Moderator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddModerator<'info> {
+    pub fn add_moderator(&mut self, authority: Pubkey, bump: u8) -> Result<()> {
+        self.moderator.set_inner(Moderator {
+            authority,
+            reports_moderated: 0,
+            added_at: Clock::get()?.unix_timestamp,
+            bump,
+        });
+
+        emit!(ModeratorAdded {
+            moderator: self.moderator.key(),
+            authority,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveModerator<'info> {
+    /// The admin removing the moderator; receives the account's rent
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The moderator to remove
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"moderator", moderator.authority.as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Account<'info, Moderator>,
+}
+
+impl<'info> RemoveModerator<'info> {
+    pub fn remove_moderator(&mut self) -> Result<()> {
+        emit!(ModeratorRemoved {
+            moderator: self.moderator.key(),
+            reports_moderated: self.moderator.reports_moderated,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ModerateReport<'info> {
+    /// The moderator's signing key; pays for any attribute growth
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The moderator deciding the report
+    #[account(
+        mut,
+        seeds = [b"moderator", authority.key().as_ref()],
+        bump = moderator.bump,
+    )]
+    pub moderator: Account<'info, Moderator>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The asset documenting the report
+    /// CHECK: Must match the report; validated by the MPL Core program
+    #[account(mut)]
+    pub asset: UncheckedAccount<'info>,
+
+    /// The report being moderated
+    #[account(
+        mut,
+        seeds = [b"scam_report", asset.key().as_ref()],
+        bump = scam_report.bump,
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// The reporter's stake, slashed if the report is rejected
+    /// CHECK: May not exist if the reporter didn't stake; read in the handler
+    #[account(
+        mut,
+        seeds = [b"report_stake", asset.key().as_ref()],
+        bump
+    )]
+    pub report_stake: UncheckedAccount<'info>,
+
+    /// Receives slashed stakes
+    /// CHECK: This is the anti-scam treasury PDA
+    #[account(
+        mut,
+        seeds = [b"treasury_anti_scam"],
+        bump = config.antiscam_treasury_bump,
+    )]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// The collection the asset belongs to
+    /// CHECK: Must be one of the program's collections; validated by the MPL Core program
+    #[account(
+        mut,
+        constraint = collection.key() == config.standard_collection
+            || collection.key() == config.scammed_collection
+            || collection_record.is_some()
+            @ crate::error::RuggedError::Unauthorized
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// Registry entry of `collection`, required unless it is bound to a config slot
+    #[account(
+        seeds = [b"collection_record", collection.key().as_ref()],
+        bump = collection_record.bump,
+    )]
+    pub collection_record: Option<Box<Account<'info, CollectionRecord>>>,
+
+    /// The program's update authority PDA, which holds the asset's freeze and burn delegates
+    /// CHECK: This is a PDA that only this program can sign for
+    #[account(
+        seeds = [b"upd_auth"],
+        bump = config.update_authority_bump,
+    )]
+    pub update_authority_pda: UncheckedAccount<'info>,
+
+    /// CHECK: This is the ID of the Metaplex Core program
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ModerateReport<'info> {
+    /// Thaws the asset, marks the report approved and counts the approval in its
+    /// scammer profiles, passed in `profile_accounts` in the report's order
+    pub fn approve_report(&mut self, profile_accounts: &[AccountInfo]) -> Result<()> {
+        self.record_decision(ReportStatus::Approved, None)?;
+        update_scammer_profiles(profile_accounts, &self.scam_report, |profile| {
+            profile.approve_report()
+        })?;
+
+        let asset = self.moderated_asset();
+        asset.set_frozen(false)?;
+        asset.set_status_attributes(ReportStatus::Approved, None)?;
+
+        emit!(ReportModerated {
+            asset: self.asset.key(),
+            moderator: self.moderator.key(),
+            status: ReportStatus::Approved,
+            rejection_reason: None,
+            burned: false,
+        });
+
+        Ok(())
+    }
+
+    /// Marks the report rejected and takes it out of its scammer profiles, passed in
+    /// `profile_accounts` in the report's order, then burns the asset or leaves it frozen
+    pub fn reject_report(
+        &mut self,
+        profile_accounts: &[AccountInfo],
+        reason: RejectionReason,
+        burn: bool,
+    ) -> Result<()> {
+        let previous_status = self.scam_report.status;
+        self.record_decision(ReportStatus::Rejected, Some(reason))?;
+        let report = &self.scam_report;
+        update_scammer_profiles(profile_accounts, report, |profile| {
+            profile.remove_report(report, false);
+            Ok(())
+        })?;
+        slash_report_stake(
+            &self.report_stake.to_account_info(),
+            &self.antiscam_treasury.to_account_info(),
+        )?;
+
+        let asset = self.moderated_asset();
+        if burn {
+            asset.burn(previous_status)?;
+        } else {
+            asset.set_status_attributes(ReportStatus::Rejected, Some(reason))?;
+        }
+
+        emit!(ReportModerated {
+            asset: self.asset.key(),
+            moderator: self.moderator.key(),
+            status: ReportStatus::Rejected,
+            rejection_reason: Some(reason),
+            burned: burn,
+        });
+
+        Ok(())
+    }
+
+    fn record_decision(
+        &mut self,
+        status: ReportStatus,
+        rejection_reason: Option<RejectionReason>,
+    ) -> Result<()> {
+        require!(
+            self.scam_report.status == ReportStatus::Pending,
+            RuggedError::ReportAlreadyModerated
+        );
+
+        self.scam_report.status = status;
+        self.scam_report.moderator = self.moderator.key();
+        self.scam_report.moderated_at = Clock::get()?.unix_timestamp;
+        self.scam_report.rejection_reason = rejection_reason;
+
+        self.moderator.reports_moderated = self
+            .moderator
+            .reports_moderated
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    fn moderated_asset(&self) -> ModeratedAsset<'_, 'info> {
+        ModeratedAsset {
+            asset: self.asset.as_ref(),
+            collection: self.collection.as_ref(),
+            update_authority: self.update_authority_pda.as_ref(),
+            update_authority_bump: self.config.update_authority_bump,
+            payer: self.authority.as_ref(),
+            system_program: self.system_program.as_ref(),
+            mpl_core_program: self.mpl_core_program.as_ref(),
+        }
+    }
+}
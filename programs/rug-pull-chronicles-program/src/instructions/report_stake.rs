@@ -0,0 +1,55 @@
+use crate::error::RuggedError;
+use crate::events::ReportStakeReleased;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReleaseStake<'info> {
+    /// The reporter getting the stake and the escrow's rent back
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// The staked report
+    #[account(
+        seeds = [b"scam_report", report_stake.asset.as_ref()],
+        bump = scam_report.bump,
+    )]
+    pub scam_report: Box<Account<'info, ScamReport>>,
+
+    /// The escrow, closed once released
+    #[account(
+        mut,
+        close = reporter,
+        has_one = reporter,
+        seeds = [b"report_stake", report_stake.asset.as_ref()],
+        bump = report_stake.bump,
+    )]
+    pub report_stake: Account<'info, ReportStake>,
+}
+
+impl<'info> ReleaseStake<'info> {
+    /// Returns the stake once the report is approved and the lock period is over, or
+    /// once a report nobody moderated reaches its pending expiry. Open challenges
+    /// hold it a while longer, see `ScamReport::stake_held_until`.
+    pub fn release_stake(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let release_at = self
+            .report_stake
+            .release_at(self.scam_report.status)
+            .filter(|_| self.report_stake.status == StakeStatus::Locked)
+            .ok_or(RuggedError::StakeLocked)?;
+        require!(now >= release_at, RuggedError::StakeLocked);
+        require!(
+            now >= self.scam_report.stake_held_until(release_at),
+            RuggedError::ReportChallenged
+        );
+
+        emit!(ReportStakeReleased {
+            asset: self.report_stake.asset,
+            reporter: self.reporter.key(),
+            amount: self.report_stake.amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+use crate::error::RuggedError;
+use crate::events::TaxonomyEntryAdded;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitTaxonomy<'info> {
+    /// The admin creating the taxonomy; pays for the account
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The taxonomy, one per config
+    #[account(
+        init,
+        payer = admin,
+        space = Taxonomy::default_space(),
+        seeds = [b"taxonomy", config.key().as_ref()],
+        bump
+    )]
+    pub taxonomy: Account<'info, Taxonomy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitTaxonomy<'info> {
+    pub fn init_taxonomy(&mut self, bump: u8) -> Result<()> {
+        self.taxonomy.set_inner(Taxonomy {
+            config: self.config.key(),
+            platform_categories: Taxonomy::default_platform_categories(),
+            attack_types: Taxonomy::default_attack_types(),
+            bump,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(kind: TaxonomyKind, label: String)]
+pub struct AddTaxonomyEntry<'info> {
+    /// The admin extending the taxonomy; pays for the extra space
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The taxonomy, grown to fit the new entry
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [b"taxonomy", config.key().as_ref()],
+        bump = taxonomy.bump,
+        realloc = taxonomy.space() + TaxonomyEntry::space(&label),
+        realloc::payer = admin,
+        realloc::zero = false,
+    )]
+    pub taxonomy: Account<'info, Taxonomy>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddTaxonomyEntry<'info> {
+    pub fn add_taxonomy_entry(&mut self, kind: TaxonomyKind, label: String) -> Result<()> {
+        require!(
+            self.taxonomy.is_valid_new_label(kind, &label),
+            RuggedError::InvalidTaxonomyEntry
+        );
+
+        let list = self.taxonomy.list_mut(kind);
+        let id = list.len() as u16;
+        list.push(TaxonomyEntry {
+            id,
+            label: label.clone(),
+            deprecated: false,
+        });
+
+        emit!(TaxonomyEntryAdded { kind, id, label });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateTaxonomy<'info> {
+    /// The admin managing the taxonomy
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The taxonomy to update
+    #[account(
+        mut,
+        has_one = config,
+        seeds = [b"taxonomy", config.key().as_ref()],
+        bump = taxonomy.bump,
+    )]
+    pub taxonomy: Account<'info, Taxonomy>,
+}
+
+impl<'info> UpdateTaxonomy<'info> {
+    /// Deprecates or restores an entry. Entries are never deleted, so ids stored in
+    /// existing reports keep resolving to their label.
+    pub fn set_taxonomy_entry_deprecated(
+        &mut self,
+        kind: TaxonomyKind,
+        id: u16,
+        deprecated: bool,
+    ) -> Result<()> {
+        let entry = self
+            .taxonomy
+            .list_mut(kind)
+            .get_mut(id as usize)
+            .ok_or(RuggedError::InvalidTaxonomyEntry)?;
+        entry.deprecated = deprecated;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
-use crate::error::CustomError;
-use crate::state::config::Config;
+use crate::error::{CustomError, RuggedError};
+use crate::events::CollectionRebound;
+use crate::state::collection::CollectionKind;
+use crate::state::config::{Config, FeeRemainderSide};
+use crate::utils::collection::{collection_max_supply, load_program_collection};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -14,21 +17,8 @@ pub struct UpdateConfig<'info> {
 }
 
 impl<'info> UpdateConfig<'info> {
-    pub fn update_collection(&mut self, collection_address: Pubkey) -> Result<()> {
-        // Update the config with the collection address
-        self.config.standard_collection = collection_address;
-        Ok(())
-    }
-
-    pub fn update_scammed_collection(&mut self, collection_address: Pubkey) -> Result<()> {
-        // Update the config with the rugged collection address
-        self.config.scammed_collection = collection_address;
-        Ok(())
-    }
-
     pub fn update_fee_settings(
         &mut self,
-        mint_fee_basis_points: u16,
         treasury_fee_percent: u8,
         antiscam_fee_percent: u8,
     ) -> Result<()> {
@@ -38,20 +28,20 @@ impl<'info> UpdateConfig<'info> {
             CustomError::InvalidFeeDistribution
         );
 
-        // Validate the mint fee is not too high
-        require!(
-            mint_fee_basis_points <= 5000, // Max 50%
-            CustomError::InvalidFeeAmount
-        );
-
         // Update the fee settings
-        self.config.mint_fee_basis_points = mint_fee_basis_points;
         self.config.treasury_fee_percent = treasury_fee_percent;
         self.config.antiscam_fee_percent = antiscam_fee_percent;
 
         Ok(())
     }
 
+    pub fn update_fee_remainder_side(&mut self, side: FeeRemainderSide) -> Result<()> {
+        // Update which treasury receives the rounding remainder of each payment split
+        self.config.fee_remainder_side = side;
+
+        Ok(())
+    }
+
     pub fn update_minimum_payment(&mut self, minimum_payment: u64) -> Result<()> {
         // Validate the minimum payment is not too low
         require!(
@@ -65,6 +55,27 @@ impl<'info> UpdateConfig<'info> {
         Ok(())
     }
 
+    pub fn update_min_report_stake(&mut self, min_report_stake: u64) -> Result<()> {
+        // 0 lets reporters mint standard NFTs without staking
+        self.config.standard_min_report_stake = min_report_stake;
+
+        Ok(())
+    }
+
+    pub fn update_victim_royalties(&mut self, basis_points: u16, victim_percent: u8) -> Result<()> {
+        // Validate the rate and the victim's share of it
+        require!(
+            basis_points <= 10_000 && victim_percent <= 100,
+            RuggedError::InvalidRoyalties
+        );
+
+        // Applies to scammed assets minted from now on
+        self.config.victim_royalty_basis_points = basis_points;
+        self.config.victim_royalty_percent = victim_percent;
+
+        Ok(())
+    }
+
     pub fn toggle_paused(&mut self) -> Result<()> {
         // Toggle the paused state
         self.config.paused = !self.config.paused;
@@ -72,3 +83,83 @@ impl<'info> UpdateConfig<'info> {
         Ok(())
     }
 }
+
+#[derive(Accounts)]
+#[instruction(collection_address: Pubkey)]
+pub struct RebindCollection<'info> {
+    /// The admin moving a config slot to another collection
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The config account holding the collection slots
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The collection the slot moves to, which must be under the `upd_auth` PDA
+    /// CHECK: Deserialized and checked in the handler
+    #[account(address = collection_address @ RuggedError::InvalidCollection)]
+    pub collection: UncheckedAccount<'info>,
+}
+
+impl<'info> RebindCollection<'info> {
+    pub fn update_collection(&mut self, collection_address: Pubkey) -> Result<()> {
+        require_keys_neq!(
+            collection_address,
+            self.config.scammed_collection,
+            RuggedError::InvalidCollection
+        );
+        let (max_supply, minted) = self.load_collection()?;
+
+        // Update the config with the collection address, its supply limit and mint count
+        let old_collection = self.config.standard_collection;
+        self.config.standard_collection = collection_address;
+        self.config.standard_collection_has_master_edition = max_supply.is_some();
+        self.config.standard_collection_max_supply = max_supply;
+        self.config.total_minted_standard = minted;
+
+        self.emit_rebound(CollectionKind::Standard, old_collection, max_supply);
+        Ok(())
+    }
+
+    pub fn update_scammed_collection(&mut self, collection_address: Pubkey) -> Result<()> {
+        require_keys_neq!(
+            collection_address,
+            self.config.standard_collection,
+            RuggedError::InvalidCollection
+        );
+        let (max_supply, minted) = self.load_collection()?;
+
+        // Update the config with the rugged collection address, its supply limit and mint count
+        let old_collection = self.config.scammed_collection;
+        self.config.scammed_collection = collection_address;
+        self.config.scammed_collection_has_master_edition = max_supply.is_some();
+        self.config.scammed_collection_max_supply = max_supply;
+        self.config.total_minted_scammed = minted;
+
+        self.emit_rebound(CollectionKind::Scammed, old_collection, max_supply);
+        Ok(())
+    }
+
+    /// Checks the new collection and returns its MasterEdition supply limit, if any,
+    /// and the number of assets ever minted into it
+    fn load_collection(&self) -> Result<(Option<u32>, u64)> {
+        let collection = load_program_collection(&self.collection, &self.config.update_authority)?;
+        Ok((
+            collection_max_supply(&self.collection),
+            u64::from(collection.num_minted),
+        ))
+    }
+
+    fn emit_rebound(&self, slot: CollectionKind, old_collection: Pubkey, max_supply: Option<u32>) {
+        emit!(CollectionRebound {
+            slot,
+            old_collection,
+            new_collection: self.collection.key(),
+            max_supply,
+        });
+    }
+}
@@ -0,0 +1,146 @@
+use crate::error::RuggedError;
+use crate::events::{VerifierAdded, VerifierRemoved, VerifierStatusChanged};
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct AddVerifier<'info> {
+    /// The admin registering the verifier; pays for the verifier account
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The new verifier, keyed by its signing key
+    #[account(
+        init,
+        payer = admin,
+        space = Verifier::INIT_SPACE,
+        seeds = [b"verifier", authority.as_ref()],
+        bump
+    )]
+    pub verifier: Account<'info, Verifier>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddVerifier<'info> {
+    pub fn add_verifier(
+        &mut self,
+        authority: Pubkey,
+        name: String,
+        scope: u8,
+        bump: u8,
+    ) -> Result<()> {
+        require!(
+            Verifier::is_valid(&name, scope),
+            RuggedError::InvalidVerifierSettings
+        );
+
+        self.verifier.set_inner(Verifier {
+            authority,
+            name: name.clone(),
+            scope,
+            active: true,
+            verifications_issued: 0,
+            registered_at: Clock::get()?.unix_timestamp,
+            bump,
+        });
+
+        emit!(VerifierAdded {
+            verifier: self.verifier.key(),
+            authority,
+            name,
+            scope,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UpdateVerifier<'info> {
+    /// The admin managing the verifier
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The verifier to update
+    #[account(
+        mut,
+        seeds = [b"verifier", verifier.authority.as_ref()],
+        bump = verifier.bump,
+    )]
+    pub verifier: Account<'info, Verifier>,
+}
+
+impl<'info> UpdateVerifier<'info> {
+    /// Stops the verifier from issuing verifications and stops honouring the ones it issued
+    pub fn suspend_verifier(&mut self) -> Result<()> {
+        self.set_active(false)
+    }
+
+    /// Lifts a suspension, restoring the verifier's verifications
+    pub fn reinstate_verifier(&mut self) -> Result<()> {
+        self.set_active(true)
+    }
+
+    fn set_active(&mut self, active: bool) -> Result<()> {
+        self.verifier.active = active;
+
+        emit!(VerifierStatusChanged {
+            verifier: self.verifier.key(),
+            active,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RemoveVerifier<'info> {
+    /// The admin removing the verifier; receives the account's rent
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The verifier to remove
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"verifier", verifier.authority.as_ref()],
+        bump = verifier.bump,
+    )]
+    pub verifier: Account<'info, Verifier>,
+}
+
+impl<'info> RemoveVerifier<'info> {
+    pub fn remove_verifier(&mut self) -> Result<()> {
+        // Closing the account revokes every verification it issued: mints can no longer
+        // load the issuing verifier, and a re-registration starts a new `registered_at`
+        emit!(VerifierRemoved {
+            verifier: self.verifier.key(),
+            verifications_issued: self.verifier.verifications_issued,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use crate::constants::VERIFICATION_SEED;
+use crate::events::{VictimVerificationRevoked, VictimVerified};
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct VerifyVictim<'info> {
+    /// The verifier's signing key; pays for the verification record
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The registered verifier vouching for the victim
+    #[account(
+        mut,
+        seeds = [b"verifier", authority.key().as_ref()],
+        bump = verifier.bump,
+        constraint = verifier.can_verify(VERIFIER_SCOPE_SCAMMED) @ crate::error::RuggedError::InvalidVerifier,
+    )]
+    pub verifier: Account<'info, Verifier>,
+
+    /// The victim's verification record
+    #[account(
+        init,
+        payer = authority,
+        space = VictimVerification::INIT_SPACE,
+        seeds = [VERIFICATION_SEED, wallet.as_ref()],
+        bump
+    )]
+    pub victim_verification: Account<'info, VictimVerification>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> VerifyVictim<'info> {
+    pub fn verify_victim(&mut self, wallet: Pubkey, bump: u8) -> Result<()> {
+        let verified_at = Clock::get()?.unix_timestamp;
+
+        self.victim_verification.set_inner(VictimVerification {
+            wallet,
+            verifier: self.verifier.key(),
+            verified_by: self.authority.key(),
+            verified_at,
+            bump,
+        });
+
+        self.verifier.verifications_issued = self
+            .verifier
+            .verifications_issued
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+
+        emit!(VictimVerified {
+            wallet,
+            verifier: self.verifier.key(),
+            verified_by: self.authority.key(),
+            verified_at,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RevokeVictimVerification<'info> {
+    /// The admin, or the verifier key that signed the verification
+    pub authority: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The verification to revoke, closed to the verifier key that paid for it
+    #[account(
+        mut,
+        close = verified_by,
+        has_one = verified_by,
+        seeds = [VERIFICATION_SEED, victim_verification.wallet.as_ref()],
+        bump = victim_verification.bump,
+        constraint = authority.key() == config.admin
+            || authority.key() == victim_verification.verified_by
+            @ crate::error::RuggedError::Unauthorized,
+    )]
+    pub victim_verification: Account<'info, VictimVerification>,
+
+    /// Receives the verification's rent
+    /// CHECK: Checked against the verification
+    #[account(mut)]
+    pub verified_by: UncheckedAccount<'info>,
+}
+
+impl<'info> RevokeVictimVerification<'info> {
+    /// Closes the verification, so the wallet can be verified again
+    pub fn revoke_victim_verification(&mut self) -> Result<()> {
+        emit!(VictimVerificationRevoked {
+            wallet: self.victim_verification.wallet,
+            verifier: self.victim_verification.verifier,
+            revoked_by: self.authority.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,301 @@
+use crate::events::{TreasuryTokenWithdrawn, TreasuryWithdrawn};
+use crate::state::accepted_mint::AcceptedMint;
+use crate::state::config::Config;
+use crate::utils::treasury::{withdraw_from_treasury, withdraw_token_from_treasury};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    /// The admin authorizing the withdrawal
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// General-ops treasury PDA
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = config.treasury_bump,
+    )]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Account receiving the withdrawn lamports
+    /// CHECK: Any account can receive lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAntiscam<'info> {
+    /// The admin authorizing the withdrawal
+    #[account(mut, constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        mut,
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Anti-scam treasury PDA
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(
+        mut,
+        constraint = config.disbursement_council == Pubkey::default()
+            @ crate::error::CustomError::AntiscamTreasuryGoverned,
+        seeds = [b"treasury_anti_scam"],
+        bump = config.antiscam_treasury_bump,
+    )]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// Account receiving the withdrawn lamports
+    /// CHECK: Any account can receive lamports
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasuryToken<'info> {
+    /// The admin authorizing the withdrawal
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The SPL mint to withdraw
+    #[account(mint::token_program = token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// The accepted-mint record, holding the mint's withdrawn totals
+    #[account(
+        mut,
+        constraint = accepted_mint.token_program == token_program.key()
+            @ crate::error::CustomError::PaymentMintNotAccepted,
+        seeds = [b"accepted_mint", config.key().as_ref(), payment_mint.key().as_ref()],
+        bump = accepted_mint.bump,
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    /// General-ops treasury PDA, owner of the treasury token account
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(seeds = [b"treasury"], bump = config.treasury_bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// Treasury's associated token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account receiving the withdrawn tokens
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAntiscamToken<'info> {
+    /// The admin authorizing the withdrawal
+    #[account(constraint = admin.key() == config.admin @ crate::error::RuggedError::Unauthorized)]
+    pub admin: Signer<'info>,
+
+    /// The program's config account
+    #[account(
+        seeds = [b"config", config.seed.to_le_bytes().as_ref()],
+        bump = config.config_bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    /// The SPL mint to withdraw
+    #[account(mint::token_program = token_program)]
+    pub payment_mint: InterfaceAccount<'info, Mint>,
+
+    /// The accepted-mint record, holding the mint's withdrawn totals
+    #[account(
+        mut,
+        constraint = accepted_mint.token_program == token_program.key()
+            @ crate::error::CustomError::PaymentMintNotAccepted,
+        seeds = [b"accepted_mint", config.key().as_ref(), payment_mint.key().as_ref()],
+        bump = accepted_mint.bump,
+    )]
+    pub accepted_mint: Account<'info, AcceptedMint>,
+
+    /// Anti-scam treasury PDA, owner of the anti-scam token account
+    /// CHECK: This is a PDA owned by this program, verified with the bump stored in config
+    #[account(
+        constraint = config.disbursement_council == Pubkey::default()
+            @ crate::error::CustomError::AntiscamTreasuryGoverned,
+        seeds = [b"treasury_anti_scam"],
+        bump = config.antiscam_treasury_bump,
+    )]
+    pub antiscam_treasury: UncheckedAccount<'info>,
+
+    /// Anti-scam treasury's associated token account for the mint
+    #[account(
+        mut,
+        associated_token::mint = payment_mint,
+        associated_token::authority = antiscam_treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub antiscam_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account receiving the withdrawn tokens
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawTreasury<'info> {
+    pub fn withdraw_treasury(&mut self, amount: u64) -> Result<()> {
+        let remaining_balance = withdraw_from_treasury(
+            &self.treasury.to_account_info(),
+            &self.recipient.to_account_info(),
+            amount,
+        )?;
+
+        // Record the withdrawal for reconciliation
+        self.config.total_withdrawn_treasury = self
+            .config
+            .total_withdrawn_treasury
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(TreasuryWithdrawn {
+            treasury: self.treasury.key(),
+            recipient: self.recipient.key(),
+            amount,
+            remaining_balance,
+            total_withdrawn: self.config.total_withdrawn_treasury,
+            authority: self.admin.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> WithdrawAntiscam<'info> {
+    pub fn withdraw_antiscam(&mut self, amount: u64) -> Result<()> {
+        let remaining_balance = withdraw_from_treasury(
+            &self.antiscam_treasury.to_account_info(),
+            &self.recipient.to_account_info(),
+            amount,
+        )?;
+
+        // Record the withdrawal for reconciliation
+        self.config.total_withdrawn_antiscam = self
+            .config
+            .total_withdrawn_antiscam
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(TreasuryWithdrawn {
+            treasury: self.antiscam_treasury.key(),
+            recipient: self.recipient.key(),
+            amount,
+            remaining_balance,
+            total_withdrawn: self.config.total_withdrawn_antiscam,
+            authority: self.admin.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> WithdrawTreasuryToken<'info> {
+    pub fn withdraw_treasury_token(&mut self, amount: u64) -> Result<()> {
+        let remaining_balance = withdraw_token_from_treasury(
+            &self.treasury.to_account_info(),
+            &[b"treasury", &[self.config.treasury_bump]],
+            &mut self.treasury_token_account,
+            &self.recipient,
+            &self.payment_mint,
+            &self.token_program,
+            amount,
+        )?;
+
+        // Record the withdrawal for reconciliation
+        self.accepted_mint.total_withdrawn_treasury = self
+            .accepted_mint
+            .total_withdrawn_treasury
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(TreasuryTokenWithdrawn {
+            treasury: self.treasury.key(),
+            mint: self.payment_mint.key(),
+            recipient: self.recipient.key(),
+            amount,
+            remaining_balance,
+            total_withdrawn: self.accepted_mint.total_withdrawn_treasury,
+            authority: self.admin.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
+
+impl<'info> WithdrawAntiscamToken<'info> {
+    pub fn withdraw_antiscam_token(&mut self, amount: u64) -> Result<()> {
+        let remaining_balance = withdraw_token_from_treasury(
+            &self.antiscam_treasury.to_account_info(),
+            &[b"treasury_anti_scam", &[self.config.antiscam_treasury_bump]],
+            &mut self.antiscam_token_account,
+            &self.recipient,
+            &self.payment_mint,
+            &self.token_program,
+            amount,
+        )?;
+
+        // Record the withdrawal for reconciliation
+        self.accepted_mint.total_withdrawn_antiscam = self
+            .accepted_mint
+            .total_withdrawn_antiscam
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        emit!(TreasuryTokenWithdrawn {
+            treasury: self.antiscam_treasury.key(),
+            mint: self.payment_mint.key(),
+            recipient: self.recipient.key(),
+            amount,
+            remaining_balance,
+            total_withdrawn: self.accepted_mint.total_withdrawn_antiscam,
+            authority: self.admin.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+}
@@ -2,19 +2,37 @@
 use anchor_lang::prelude::*;
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
+use instructions::accepted_mint::*;
 use instructions::add_collection_plugin::*;
+use instructions::add_evidence::*;
 use instructions::add_freeze_delegate::*;
+use instructions::challenge::*;
+use instructions::check_address::*;
+use instructions::collection_registry::*;
 use instructions::create_collection::*;
+use instructions::disbursement::*;
+use instructions::disbursement_council::*;
+use instructions::fee_schedule::*;
 use instructions::freeze_asset::*;
 use instructions::initialize::*;
+use instructions::manage_collection::*;
+use instructions::migrate_config::*;
+use instructions::mint_into_collection::*;
 use instructions::mint_scammed_nft::*;
 use instructions::mint_standard_nft::*;
+use instructions::moderation::*;
+use instructions::report_stake::*;
+use instructions::taxonomy::*;
 use instructions::thaw_asset::*;
 use instructions::update_config::*;
+use instructions::verifier::*;
+use instructions::verify_victim::*;
+use instructions::withdraw_treasury::*;
 
 declare_id!("Fhpi7Xfc6eYZxy5ENLeW4vmRbkWfNZpeFC4Btiqf7sR8");
 
@@ -33,6 +51,7 @@ pub mod rug_pull_chronicles_program {
         max_supply: Option<u32>,
         edition_name: Option<String>,
         edition_uri: Option<String>,
+        target: state::CollectionTarget,
     ) -> Result<()> {
         let args = CreateCollectionArgs {
             name,
@@ -40,57 +59,360 @@ pub mod rug_pull_chronicles_program {
             max_supply,
             edition_name,
             edition_uri,
+            target,
         };
-        ctx.accounts.create_core_collection(args)
+        ctx.accounts.create_core_collection(args, &ctx.bumps)
     }
 
     pub fn update_config_collection(
-        ctx: Context<UpdateConfig>,
+        ctx: Context<RebindCollection>,
         collection_address: Pubkey,
     ) -> Result<()> {
         ctx.accounts.update_collection(collection_address)
     }
 
     pub fn update_config_rugged_collection(
-        ctx: Context<UpdateConfig>,
+        ctx: Context<RebindCollection>,
         collection_address: Pubkey,
     ) -> Result<()> {
         ctx.accounts.update_scammed_collection(collection_address)
     }
 
+    pub fn migrate_config(ctx: Context<MigrateConfig>, _seed: u64) -> Result<()> {
+        ctx.accounts.migrate_config()
+    }
+
+    pub fn register_collection(
+        ctx: Context<RegisterCollection>,
+        kind: state::CollectionKind,
+        settings: state::CollectionSettings,
+    ) -> Result<()> {
+        ctx.accounts
+            .register_collection(kind, settings, ctx.bumps.collection_record)
+    }
+
+    pub fn update_collection_record(
+        ctx: Context<UpdateCollectionRecord>,
+        settings: state::CollectionSettings,
+    ) -> Result<()> {
+        ctx.accounts.update_collection_record(settings)
+    }
+
     pub fn update_fee_settings(
         ctx: Context<UpdateConfig>,
-        mint_fee_basis_points: u16,
         treasury_fee_percent: u8,
         antiscam_fee_percent: u8,
     ) -> Result<()> {
-        ctx.accounts.update_fee_settings(
-            mint_fee_basis_points,
-            treasury_fee_percent,
-            antiscam_fee_percent,
-        )
+        ctx.accounts
+            .update_fee_settings(treasury_fee_percent, antiscam_fee_percent)
+    }
+
+    pub fn update_fee_remainder_side(
+        ctx: Context<UpdateConfig>,
+        side: state::FeeRemainderSide,
+    ) -> Result<()> {
+        ctx.accounts.update_fee_remainder_side(side)
+    }
+
+    pub fn init_fee_schedule(
+        ctx: Context<InitFeeSchedule>,
+        beneficiaries: Vec<state::FeeBeneficiary>,
+        remainder_index: u8,
+    ) -> Result<()> {
+        let bump = ctx.bumps.fee_schedule;
+        ctx.accounts
+            .init_fee_schedule(beneficiaries, remainder_index, bump)
+    }
+
+    pub fn update_fee_schedule(
+        ctx: Context<UpdateFeeSchedule>,
+        beneficiaries: Vec<state::FeeBeneficiary>,
+        remainder_index: u8,
+        active: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_fee_schedule(beneficiaries, remainder_index, active)
+    }
+
+    pub fn init_taxonomy(ctx: Context<InitTaxonomy>) -> Result<()> {
+        let bump = ctx.bumps.taxonomy;
+        ctx.accounts.init_taxonomy(bump)
+    }
+
+    pub fn add_taxonomy_entry(
+        ctx: Context<AddTaxonomyEntry>,
+        kind: state::TaxonomyKind,
+        label: String,
+    ) -> Result<()> {
+        ctx.accounts.add_taxonomy_entry(kind, label)
+    }
+
+    pub fn set_taxonomy_entry_deprecated(
+        ctx: Context<UpdateTaxonomy>,
+        kind: state::TaxonomyKind,
+        id: u16,
+        deprecated: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_taxonomy_entry_deprecated(kind, id, deprecated)
     }
 
     pub fn update_minimum_payment(ctx: Context<UpdateConfig>, minimum_payment: u64) -> Result<()> {
         ctx.accounts.update_minimum_payment(minimum_payment)
     }
 
+    pub fn update_min_report_stake(
+        ctx: Context<UpdateConfig>,
+        min_report_stake: u64,
+    ) -> Result<()> {
+        ctx.accounts.update_min_report_stake(min_report_stake)
+    }
+
+    pub fn update_victim_royalties(
+        ctx: Context<UpdateConfig>,
+        basis_points: u16,
+        victim_percent: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .update_victim_royalties(basis_points, victim_percent)
+    }
+
     pub fn toggle_paused(ctx: Context<UpdateConfig>) -> Result<()> {
         ctx.accounts.toggle_paused()
     }
 
+    pub fn add_accepted_mint(ctx: Context<AddAcceptedMint>, minimum_payment: u64) -> Result<()> {
+        let bump = ctx.bumps.accepted_mint;
+        ctx.accounts.add_accepted_mint(minimum_payment, bump)
+    }
+
+    pub fn update_accepted_mint(
+        ctx: Context<UpdateAcceptedMint>,
+        minimum_payment: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        ctx.accounts.update_accepted_mint(minimum_payment, enabled)
+    }
+
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_treasury(amount)
+    }
+
+    pub fn withdraw_antiscam(ctx: Context<WithdrawAntiscam>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_antiscam(amount)
+    }
+
+    pub fn withdraw_treasury_token(ctx: Context<WithdrawTreasuryToken>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_treasury_token(amount)
+    }
+
+    pub fn withdraw_antiscam_token(ctx: Context<WithdrawAntiscamToken>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_antiscam_token(amount)
+    }
+
+    pub fn init_disbursement_council(
+        ctx: Context<InitDisbursementCouncil>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+    ) -> Result<()> {
+        let bump = ctx.bumps.council;
+        ctx.accounts
+            .init_disbursement_council(approvers, threshold, min_delay, bump)
+    }
+
+    pub fn update_disbursement_council(
+        ctx: Context<UpdateDisbursementCouncil>,
+        approvers: Vec<Pubkey>,
+        threshold: u8,
+        min_delay: i64,
+    ) -> Result<()> {
+        ctx.accounts.update_disbursement_council(
+            ctx.remaining_accounts,
+            approvers,
+            threshold,
+            min_delay,
+        )
+    }
+
+    pub fn propose_disbursement(
+        ctx: Context<ProposeDisbursement>,
+        recipient: Pubkey,
+        mint: Option<Pubkey>,
+        amount: u64,
+        memo: String,
+        execute_after: i64,
+    ) -> Result<()> {
+        let bump = ctx.bumps.disbursement;
+        ctx.accounts
+            .propose_disbursement(recipient, mint, amount, memo, execute_after, bump)
+    }
+
+    pub fn approve_disbursement(ctx: Context<ApproveDisbursement>) -> Result<()> {
+        ctx.accounts.approve_disbursement()
+    }
+
+    pub fn cancel_disbursement(ctx: Context<CancelDisbursement>) -> Result<()> {
+        ctx.accounts.cancel_disbursement()
+    }
+
+    pub fn execute_disbursement(ctx: Context<ExecuteDisbursement>) -> Result<()> {
+        ctx.accounts.execute_disbursement()
+    }
+
+    pub fn execute_token_disbursement(ctx: Context<ExecuteTokenDisbursement>) -> Result<()> {
+        ctx.accounts.execute_token_disbursement()
+    }
+
+    pub fn add_verifier(
+        ctx: Context<AddVerifier>,
+        authority: Pubkey,
+        name: String,
+        scope: u8,
+    ) -> Result<()> {
+        let bump = ctx.bumps.verifier;
+        ctx.accounts.add_verifier(authority, name, scope, bump)
+    }
+
+    pub fn suspend_verifier(ctx: Context<UpdateVerifier>) -> Result<()> {
+        ctx.accounts.suspend_verifier()
+    }
+
+    pub fn reinstate_verifier(ctx: Context<UpdateVerifier>) -> Result<()> {
+        ctx.accounts.reinstate_verifier()
+    }
+
+    pub fn remove_verifier(ctx: Context<RemoveVerifier>) -> Result<()> {
+        ctx.accounts.remove_verifier()
+    }
+
+    pub fn verify_victim(ctx: Context<VerifyVictim>, wallet: Pubkey) -> Result<()> {
+        let bump = ctx.bumps.victim_verification;
+        ctx.accounts.verify_victim(wallet, bump)
+    }
+
+    pub fn revoke_victim_verification(ctx: Context<RevokeVictimVerification>) -> Result<()> {
+        ctx.accounts.revoke_victim_verification()
+    }
+
+    pub fn add_evidence(
+        ctx: Context<AddEvidence>,
+        entries: Vec<state::EvidenceEntryArgs>,
+    ) -> Result<()> {
+        ctx.accounts.add_evidence(entries)
+    }
+
+    pub fn add_moderator(ctx: Context<AddModerator>, authority: Pubkey) -> Result<()> {
+        let bump = ctx.bumps.moderator;
+        ctx.accounts.add_moderator(authority, bump)
+    }
+
+    pub fn remove_moderator(ctx: Context<RemoveModerator>) -> Result<()> {
+        ctx.accounts.remove_moderator()
+    }
+
+    pub fn approve_report(ctx: Context<ModerateReport>) -> Result<()> {
+        ctx.accounts.approve_report(ctx.remaining_accounts)
+    }
+
+    pub fn reject_report(
+        ctx: Context<ModerateReport>,
+        reason: state::RejectionReason,
+        burn: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .reject_report(ctx.remaining_accounts, reason, burn)
+    }
+
+    pub fn open_challenge(
+        ctx: Context<OpenChallenge>,
+        reason: String,
+        evidence_uri: String,
+        bond_lamports: u64,
+    ) -> Result<()> {
+        let bump = ctx.bumps.challenge;
+        ctx.accounts
+            .open_challenge(reason, evidence_uri, bond_lamports, bump)
+    }
+
+    pub fn resolve_challenge(ctx: Context<ResolveChallenge>, upheld: bool) -> Result<()> {
+        ctx.accounts
+            .resolve_challenge(ctx.remaining_accounts, upheld)
+    }
+
+    pub fn expire_challenge(ctx: Context<ExpireChallenge>) -> Result<()> {
+        ctx.accounts.expire_challenge()
+    }
+
+    pub fn release_stake(ctx: Context<ReleaseStake>) -> Result<()> {
+        ctx.accounts.release_stake()
+    }
+
+    pub fn check_address(
+        ctx: Context<CheckAddress>,
+        _chain_id: u64,
+        _address: Pubkey,
+    ) -> Result<state::AddressRiskSummary> {
+        ctx.accounts.check_address()
+    }
+
     pub fn add_collection_royalties(
         ctx: Context<AddCollectionPlugin>,
         basis_points: u16,
         creators: Vec<CreatorInput>,
+        rule_set: RoyaltyRuleSet,
     ) -> Result<()> {
         let args = AddCollectionPluginArgs {
             basis_points,
             creators,
+            rule_set,
         };
         ctx.accounts.add_collection_royalties(args)
     }
 
+    pub fn update_collection_royalties(
+        ctx: Context<AddCollectionPlugin>,
+        basis_points: u16,
+        creators: Vec<CreatorInput>,
+        rule_set: RoyaltyRuleSet,
+    ) -> Result<()> {
+        let args = AddCollectionPluginArgs {
+            basis_points,
+            creators,
+            rule_set,
+        };
+        ctx.accounts.update_collection_royalties(args)
+    }
+
+    pub fn update_collection_metadata(
+        ctx: Context<ManageCollection>,
+        name: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts.update_collection_metadata(name, uri)
+    }
+
+    pub fn add_collection_plugin(
+        ctx: Context<ManageCollection>,
+        plugin: CollectionPluginArgs,
+    ) -> Result<()> {
+        ctx.accounts.add_collection_plugin(plugin)
+    }
+
+    pub fn update_collection_plugin(
+        ctx: Context<ManageCollection>,
+        plugin: CollectionPluginArgs,
+    ) -> Result<()> {
+        ctx.accounts.update_collection_plugin(plugin)
+    }
+
+    pub fn remove_collection_plugin(
+        ctx: Context<ManageCollection>,
+        plugin_kind: CollectionPluginKind,
+    ) -> Result<()> {
+        ctx.accounts.remove_collection_plugin(plugin_kind)
+    }
+
     pub fn add_freeze_delegate(
         ctx: Context<AddFreezePlugin>,
         frozen: bool,
@@ -108,31 +430,65 @@ pub mod rug_pull_chronicles_program {
         ctx.accounts.thaw_asset()
     }
 
-    pub fn mint_standard_nft(
-        ctx: Context<MintStandardNft>,
+    pub fn mint_standard_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintStandardNft<'info>>,
         name: String,
         uri: String,
-        scam_year: String,
-        usd_amount_stolen: String,
-        platform_category: String,
-        type_of_attack: String,
+        report: state::ScamReportArgs,
+        payment_amount: u64,
+        stake_amount: u64,
     ) -> Result<()> {
         ctx.accounts.mint_core_asset(
+            ctx.remaining_accounts,
             name,
             uri,
-            scam_year,
-            usd_amount_stolen,
-            platform_category,
-            type_of_attack,
+            report,
+            payment_amount,
+            stake_amount,
+            &ctx.bumps,
         )
     }
 
-    pub fn mint_scammed_nft(
-        ctx: Context<MintScammedNft>,
+    #[allow(clippy::too_many_arguments)]
+    pub fn mint_scammed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintScammedNft<'info>>,
         name: String,
         uri: String,
         scam_details: String,
+        report: state::ScamReportArgs,
+        payment_amount: u64,
+        attestation: Option<state::VictimAttestation>,
+        safe_destination: Option<state::SafeDestination>,
     ) -> Result<()> {
-        ctx.accounts.mint_core_asset(name, uri, scam_details)
+        ctx.accounts.mint_core_asset(
+            ctx.remaining_accounts,
+            name,
+            uri,
+            scam_details,
+            report,
+            payment_amount,
+            attestation,
+            safe_destination,
+            &ctx.bumps,
+        )
+    }
+
+    pub fn mint_into_collection<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintIntoCollection<'info>>,
+        name: String,
+        uri: String,
+        report: state::ScamReportArgs,
+        payment_amount: u64,
+        stake_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.mint_core_asset(
+            ctx.remaining_accounts,
+            name,
+            uri,
+            report,
+            payment_amount,
+            stake_amount,
+            &ctx.bumps,
+        )
     }
 }
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+/// An SPL token (Token or Token-2022) that can be used to pay mint fees.
+///
+/// One record exists per accepted mint, seeded by the config and the mint, so the
+/// set of these PDAs forms the config's accepted-mint list.
+#[account]
+pub struct AcceptedMint {
+    /// Config account this payment option belongs to
+    pub config: Pubkey,
+    /// The SPL mint being accepted
+    pub mint: Pubkey,
+    /// Token program that owns the mint (Token or Token-2022)
+    pub token_program: Pubkey,
+    /// Minimum payment in the mint's base units
+    pub minimum_payment: u64,
+    /// Whether mint handlers currently accept this token
+    pub enabled: bool,
+    /// Running total withdrawn from the treasury's token account, in base units
+    pub total_withdrawn_treasury: u64,
+    /// Running total paid out of the anti-scam treasury's token account, in base units
+    pub total_withdrawn_antiscam: u64,
+    /// AcceptedMint PDA bump
+    pub bump: u8,
+}
+
+impl Space for AcceptedMint {
+    // 8   — Anchor discriminator
+    // 96  — config, mint and token_program (Pubkey × 3)
+    // 8   — minimum_payment (u64)
+    // 1   — enabled (bool)
+    // 16  — total_withdrawn_treasury and total_withdrawn_antiscam (u64 × 2)
+    // 1   — bump (u8)
+    const INIT_SPACE: usize = 8 + (3 * 32) + 8 + 1 + 16 + 1;
+}
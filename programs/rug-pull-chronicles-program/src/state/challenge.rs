@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+/// Minimum bond, in lamports, to open a challenge (0.1 SOL)
+pub const MIN_CHALLENGE_BOND: u64 = 100_000_000;
+/// How long moderators have to resolve a challenge (14 days)
+pub const CHALLENGE_RESOLUTION_PERIOD: i64 = 14 * 24 * 60 * 60;
+/// Maximum length (in bytes) of a challenge's reason
+pub const MAX_CHALLENGE_REASON_LEN: usize = 200;
+/// Maximum length (in bytes) of a challenge's evidence URI
+pub const MAX_CHALLENGE_URI_LEN: usize = 200;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeStatus {
+    /// Waiting for a moderator or the admin
+    Open,
+    /// The report was retracted and the bond refunded
+    Upheld,
+    /// The report stands and the bond went to the anti-scam treasury
+    Rejected,
+    /// Nobody resolved it before the deadline; the bond was refunded
+    Expired,
+}
+
+/// A dispute of a report, backed by a SOL bond held in this account.
+///
+/// Seeded by `[b"challenge", asset, challenger]`, so each wallet has at most one open
+/// challenge per report. Resolving or expiring the challenge closes it to the
+/// challenger, and `ChallengeResolved` records the outcome.
+#[account]
+pub struct Challenge {
+    /// The asset whose report is challenged
+    pub asset: Pubkey,
+    pub challenger: Pubkey,
+    /// Lamports posted as bond, on top of the account's rent
+    pub bond_lamports: u64,
+    pub reason: String,
+    /// Where the challenger's counter-evidence lives
+    pub evidence_uri: String,
+    pub opened_at: i64,
+    /// Last moment a moderator or the admin can resolve the challenge
+    pub resolution_deadline: i64,
+    pub status: ChallengeStatus,
+    /// Challenge PDA bump
+    pub bump: u8,
+}
+
+impl Challenge {
+    pub fn is_valid(reason: &str, evidence_uri: &str, bond_lamports: u64) -> bool {
+        !reason.is_empty()
+            && reason.len() <= MAX_CHALLENGE_REASON_LEN
+            && !evidence_uri.is_empty()
+            && evidence_uri.len() <= MAX_CHALLENGE_URI_LEN
+            && bond_lamports >= MIN_CHALLENGE_BOND
+    }
+}
+
+impl Space for Challenge {
+    // 8    — Anchor discriminator
+    // 64   — asset and challenger (Pubkey × 2)
+    // 8    — bond_lamports (u64)
+    // 4+N  — reason (String, up to MAX_CHALLENGE_REASON_LEN bytes)
+    // 4+N  — evidence_uri (String, up to MAX_CHALLENGE_URI_LEN bytes)
+    // 16   — opened_at and resolution_deadline (i64 × 2)
+    // 1    — status (enum)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8
+        + (2 * 32)
+        + 8
+        + (4 + MAX_CHALLENGE_REASON_LEN)
+        + (4 + MAX_CHALLENGE_URI_LEN)
+        + 16
+        + 1
+        + 1;
+}
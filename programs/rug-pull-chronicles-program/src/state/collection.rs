@@ -7,3 +7,122 @@ pub struct CollectionInfo {
     pub mint:    Pubkey,
     pub edition: Pubkey,
 }
+
+/// Which mint flow a registered collection follows
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CollectionKind {
+    /// Anyone can document a scam
+    Standard,
+    /// Only verified victims can mint
+    Scammed,
+}
+
+/// Per-collection replacements for the config's fee settings
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct CollectionFeeOverrides {
+    /// Replaces `config.minimum_payment` for SOL payments
+    pub minimum_payment: Option<u64>,
+    /// Replaces the treasury / anti-scam split; the anti-scam treasury gets the rest of 100
+    pub treasury_fee_percent: Option<u8>,
+}
+
+impl CollectionFeeOverrides {
+    // 9 — minimum_payment (1 + 8)
+    // 2 — treasury_fee_percent (1 + 1)
+    pub const SPACE: usize = 9 + 2;
+
+    pub fn is_valid(&self) -> bool {
+        self.treasury_fee_percent
+            .is_none_or(|percent| percent <= 100)
+    }
+}
+
+/// Admin-controlled settings of a registered collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CollectionSettings {
+    /// Mint cap for the collection, or `None` for no cap
+    pub max_supply: Option<u32>,
+    pub fee_overrides: CollectionFeeOverrides,
+    /// Off-chain metadata schema the collection's assets follow
+    pub schema_id: u16,
+    /// Whether `mint_into_collection` accepts mints into the collection
+    pub active: bool,
+}
+
+/// Where `create_collection` binds a new collection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub enum CollectionTarget {
+    /// The config's `standard_collection` slot
+    Standard,
+    /// The config's `scammed_collection` slot
+    Scammed,
+    /// A new `CollectionRecord` in the collection registry
+    Registry {
+        kind: CollectionKind,
+        settings: CollectionSettings,
+    },
+}
+
+/// Registry entry for a collection minted through `mint_into_collection`.
+///
+/// Seeded by `[b"collection_record", collection]`.
+#[account]
+pub struct CollectionRecord {
+    /// The mpl-core collection
+    pub collection: Pubkey,
+    pub kind: CollectionKind,
+    /// Mint cap for the collection, or `None` for no cap
+    pub max_supply: Option<u32>,
+    /// Number of assets minted through `mint_into_collection`
+    pub minted: u64,
+    pub fee_overrides: CollectionFeeOverrides,
+    /// Whether `mint_into_collection` accepts mints into the collection
+    pub active: bool,
+    /// Off-chain metadata schema the collection's assets follow
+    pub schema_id: u16,
+    pub registered_at: i64,
+    /// CollectionRecord PDA bump
+    pub bump: u8,
+}
+
+impl CollectionRecord {
+    /// Whether the record accepts one more mint
+    pub fn can_mint(&self) -> bool {
+        self.active
+            && self
+                .max_supply
+                .is_none_or(|max_supply| self.minted < max_supply as u64)
+    }
+
+    /// Applies new settings; the cap can't drop below what has already been minted
+    pub fn apply(&mut self, settings: CollectionSettings) -> bool {
+        if !self.fits_max_supply(settings.max_supply) || !settings.fee_overrides.is_valid() {
+            return false;
+        }
+
+        self.max_supply = settings.max_supply;
+        self.fee_overrides = settings.fee_overrides;
+        self.schema_id = settings.schema_id;
+        self.active = settings.active;
+        true
+    }
+
+    /// Whether `max_supply` leaves room for every asset already minted
+    pub fn fits_max_supply(&self, max_supply: Option<u32>) -> bool {
+        max_supply.is_none_or(|max_supply| max_supply as u64 >= self.minted)
+    }
+}
+
+impl Space for CollectionRecord {
+    // 8  — Anchor discriminator
+    // 32 — collection (Pubkey)
+    // 1  — kind (enum)
+    // 5  — max_supply (Option<u32>)
+    // 8  — minted (u64)
+    // 11 — fee_overrides (CollectionFeeOverrides)
+    // 1  — active (bool)
+    // 2  — schema_id (u16)
+    // 8  — registered_at (i64)
+    // 1  — bump (u8)
+    const INIT_SPACE: usize = 8 + 32 + 1 + 5 + 8 + CollectionFeeOverrides::SPACE + 1 + 2 + 8 + 1;
+}
@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
 
+/// Layout version of `Config`. Fields are only ever appended; each bump gives the new
+/// fields their defaults in `Config::migrate`.
+pub const CONFIG_VERSION: u16 = 2;
+
 /// Holds all of the "global" PDAs for Rug Pull Chronicles:
 ///   – update authority  
 ///   – fee treasuries  
@@ -34,13 +38,14 @@ pub struct Config {
     pub standard_collection: Pubkey,
     /// The rugged collection mint address
     pub scammed_collection: Pubkey,
-    /// Mint fee in basis points (e.g., 500 = 5%)
+    /// Unused since mints split the full user-chosen payment; kept so the
+    /// account layout doesn't change. Always 0 for new configs.
     pub mint_fee_basis_points: u16,
     /// Percentage of fee that goes to treasury (0-100)
     pub treasury_fee_percent: u8,
     /// Percentage of fee that goes to anti-scam treasury (0-100)
     pub antiscam_fee_percent: u8,
-    /// Minimum payment required for minting (in lamports)
+    /// Minimum payment a user must choose when minting (in lamports)
     pub minimum_payment: u64,
     /// Circuit breaker flag to pause all functionality in case of emergencies
     pub paused: bool,
@@ -58,6 +63,49 @@ pub struct Config {
     pub scammed_collection_has_master_edition: bool,
     /// Max supply for scammed collection (if Master Edition plugin applied)
     pub scammed_collection_max_supply: Option<u32>,
+    /// Total lamports withdrawn from the general-ops treasury
+    pub total_withdrawn_treasury: u64,
+    /// Total lamports withdrawn from the anti-scam treasury
+    pub total_withdrawn_antiscam: u64,
+    /// Disbursement council governing the anti-scam treasury (default when not set up).
+    /// Once set, anti-scam funds can only leave through an approved disbursement.
+    pub disbursement_council: Pubkey,
+    /// Which treasury receives the lamports left over when a payment doesn't split evenly
+    pub fee_remainder_side: FeeRemainderSide,
+    /// N-way fee schedule mint handlers must use (default when not set up)
+    pub fee_schedule: Pubkey,
+    /// Minimum stake, in lamports, `mint_standard_nft` requires; 0 makes staking optional
+    pub standard_min_report_stake: u64,
+    /// Royalty rate, in basis points, of the per-asset Royalties plugin on scammed
+    /// assets; 0 leaves them on the collection's royalties
+    pub victim_royalty_basis_points: u16,
+    /// Victim's share (0-100) of those royalties; the anti-scam treasury gets the rest
+    pub victim_royalty_percent: u8,
+}
+
+/// Recipient of the rounding remainder of a mint payment split
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum FeeRemainderSide {
+    Treasury,
+    AntiScam,
+}
+
+impl Config {
+    /// Brings a config read from an older layout, whose missing fields read as zeroes,
+    /// up to `CONFIG_VERSION`
+    pub fn migrate(&mut self) {
+        if self.version < 2 {
+            self.total_withdrawn_treasury = 0;
+            self.total_withdrawn_antiscam = 0;
+            self.disbursement_council = Pubkey::default();
+            self.fee_remainder_side = FeeRemainderSide::AntiScam;
+            self.fee_schedule = Pubkey::default();
+            self.standard_min_report_stake = 0;
+            self.victim_royalty_basis_points = 0;
+            self.victim_royalty_percent = 0;
+        }
+        self.version = CONFIG_VERSION;
+    }
 }
 
 impl Space for Config {
@@ -66,7 +114,7 @@ impl Space for Config {
     // 8   — seed (u64)
     // 7   — seven bumps (u8 × 7)
     // 5×32— five Pubkeys (update_authority, treasury, antiscam_treasury, standard_collection, scammed_collection)
-    // 2   — mint_fee_basis_points (u16)
+    // 2   — mint_fee_basis_points (u16, unused)
     // 2   — treasury_fee_percent and antiscam_fee_percent (u8 × 2)
     // 8   — minimum_payment (u64)
     // 1   — paused (bool)
@@ -74,5 +122,12 @@ impl Space for Config {
     // 2   — version (u16)
     // 2   — two booleans for master edition flags (bool × 2)
     // 10  — two Option<u32> fields (1 + 4 bytes each, where the 1 byte is for the option tag)
-    const INIT_SPACE: usize = 8 + 32 + 8 + 7 + (5 * 32) + 2 + 2 + 8 + 1 + 16 + 2 + 2 + (2 * 5);
+    // 16  — total_withdrawn_treasury and total_withdrawn_antiscam (u64 × 2)
+    // 32  — disbursement_council (Pubkey)
+    // 1   — fee_remainder_side (enum)
+    // 32  — fee_schedule (Pubkey)
+    // 8   — standard_min_report_stake (u64)
+    // 3   — victim_royalty_basis_points (u16) and victim_royalty_percent (u8)
+    const INIT_SPACE: usize =
+        8 + 32 + 8 + 7 + (5 * 32) + 2 + 2 + 8 + 1 + 16 + 2 + 2 + (2 * 5) + 16 + 32 + 1 + 32 + 8 + 3;
 }
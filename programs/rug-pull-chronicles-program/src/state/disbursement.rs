@@ -0,0 +1,156 @@
+use crate::error::CustomError;
+use anchor_lang::prelude::*;
+
+/// Maximum number of keys in the disbursement approver set
+pub const MAX_DISBURSEMENT_APPROVERS: usize = 10;
+/// Maximum memo length (in bytes) attached to a disbursement
+pub const MAX_DISBURSEMENT_MEMO_LEN: usize = 200;
+
+/// M-of-N signer set that governs the anti-scam treasury
+#[account]
+pub struct DisbursementCouncil {
+    /// Config account this council belongs to
+    pub config: Pubkey,
+    /// Keys allowed to propose, approve and cancel disbursements
+    pub approvers: Vec<Pubkey>,
+    /// Number of approvals required before a disbursement can execute
+    pub threshold: u8,
+    /// Minimum number of seconds between proposing and executing a disbursement
+    pub min_delay: i64,
+    /// Number of disbursements proposed so far, used to derive proposal PDAs
+    pub proposal_count: u64,
+    /// Council PDA bump
+    pub bump: u8,
+}
+
+impl DisbursementCouncil {
+    pub fn is_approver(&self, key: &Pubkey) -> bool {
+        self.approvers.contains(key)
+    }
+
+    /// Checks that an approver set is non-empty, unique, bounded, and that the threshold fits it
+    pub fn is_valid_approver_set(approvers: &[Pubkey], threshold: u8) -> bool {
+        if approvers.is_empty() || approvers.len() > MAX_DISBURSEMENT_APPROVERS {
+            return false;
+        }
+        if threshold == 0 || threshold as usize > approvers.len() {
+            return false;
+        }
+        approvers
+            .iter()
+            .enumerate()
+            .all(|(i, key)| !approvers[..i].contains(key))
+    }
+}
+
+impl Space for DisbursementCouncil {
+    // 8    — Anchor discriminator
+    // 32   — config (Pubkey)
+    // 4+N  — approvers (Vec<Pubkey>, up to MAX_DISBURSEMENT_APPROVERS)
+    // 1    — threshold (u8)
+    // 8    — min_delay (i64)
+    // 8    — proposal_count (u64)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8 + 32 + (4 + 32 * MAX_DISBURSEMENT_APPROVERS) + 1 + 8 + 8 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DisbursementStatus {
+    Pending,
+    Executed,
+    Cancelled,
+}
+
+/// A proposal to pay out of the anti-scam treasury
+#[account]
+pub struct Disbursement {
+    /// Council that governs this proposal
+    pub council: Pubkey,
+    /// Sequential id within the council
+    pub id: u64,
+    /// Approver that created the proposal
+    pub proposer: Pubkey,
+    /// Where the funds go on execution: a wallet for lamports, or a token account
+    /// of `mint` for token disbursements
+    pub recipient: Pubkey,
+    /// SPL mint paid out of the anti-scam treasury's token account (`None` for SOL)
+    pub mint: Option<Pubkey>,
+    /// Lamports or token base units to pay out
+    pub amount: u64,
+    /// Free-form description of what the funds are for
+    pub memo: String,
+    /// Unix timestamp before which the proposal cannot execute
+    pub execute_after: i64,
+    /// Approvers that signed off on the proposal
+    pub approvals: Vec<Pubkey>,
+    pub status: DisbursementStatus,
+    pub created_at: i64,
+    /// When the proposal was executed or cancelled (0 while pending)
+    pub resolved_at: i64,
+    /// Who executed or cancelled the proposal
+    pub resolved_by: Pubkey,
+    /// Disbursement PDA bump
+    pub bump: u8,
+}
+
+impl Disbursement {
+    /// Counts approvals from keys that are still part of the council
+    pub fn valid_approvals(&self, council: &DisbursementCouncil) -> u8 {
+        self.approvals
+            .iter()
+            .filter(|key| council.is_approver(key))
+            .count() as u8
+    }
+
+    /// Checks that the proposal is pending, approved and past its timelock.
+    /// Returns the current timestamp.
+    pub fn check_executable(&self, council: &DisbursementCouncil) -> Result<i64> {
+        require!(
+            self.status == DisbursementStatus::Pending,
+            CustomError::DisbursementNotPending
+        );
+        require!(
+            self.valid_approvals(council) >= council.threshold,
+            CustomError::InsufficientApprovals
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.execute_after,
+            CustomError::DisbursementTimelocked
+        );
+
+        Ok(now)
+    }
+}
+
+impl Space for Disbursement {
+    // 8    — Anchor discriminator
+    // 32   — council (Pubkey)
+    // 8    — id (u64)
+    // 32   — proposer (Pubkey)
+    // 32   — recipient (Pubkey)
+    // 33   — mint (Option<Pubkey>)
+    // 8    — amount (u64)
+    // 4+N  — memo (String, up to MAX_DISBURSEMENT_MEMO_LEN bytes)
+    // 8    — execute_after (i64)
+    // 4+N  — approvals (Vec<Pubkey>, up to MAX_DISBURSEMENT_APPROVERS)
+    // 1    — status (enum)
+    // 16   — created_at and resolved_at (i64 × 2)
+    // 32   — resolved_by (Pubkey)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8
+        + 32
+        + 8
+        + 32
+        + 32
+        + 33
+        + 8
+        + (4 + MAX_DISBURSEMENT_MEMO_LEN)
+        + 8
+        + (4 + 32 * MAX_DISBURSEMENT_APPROVERS)
+        + 1
+        + 16
+        + 32
+        + 1;
+}
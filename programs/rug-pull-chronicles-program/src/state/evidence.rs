@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of evidence entries per report
+pub const MAX_EVIDENCE_ENTRIES: usize = 16;
+/// Maximum length (in bytes) of an IPFS CID, in binary form
+pub const MAX_IPFS_CID_LEN: usize = 64;
+
+/// What an evidence entry's bytes are
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum EvidenceKind {
+    /// A transaction signature (64 bytes)
+    TransactionSignature,
+    /// An IPFS CID in binary form (up to MAX_IPFS_CID_LEN bytes)
+    IpfsCid,
+    /// An Arweave transaction id (32 bytes)
+    ArweaveTx,
+    /// The SHA-256 digest of a file (32 bytes)
+    Sha256,
+}
+
+impl EvidenceKind {
+    /// Whether `len` bytes is a well-formed value of this kind
+    pub fn is_valid_len(&self, len: usize) -> bool {
+        match self {
+            EvidenceKind::TransactionSignature => len == 64,
+            EvidenceKind::IpfsCid => (1..=MAX_IPFS_CID_LEN).contains(&len),
+            EvidenceKind::ArweaveTx | EvidenceKind::Sha256 => len == 32,
+        }
+    }
+}
+
+/// Evidence entry as supplied by the mint and `add_evidence` callers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EvidenceEntryArgs {
+    pub kind: EvidenceKind,
+    pub data: Vec<u8>,
+}
+
+impl EvidenceEntryArgs {
+    pub fn is_valid(&self) -> bool {
+        self.kind.is_valid_len(self.data.len())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct EvidenceEntry {
+    pub kind: EvidenceKind,
+    pub data: Vec<u8>,
+    /// Wallet that attached the entry
+    pub submitter: Pubkey,
+    pub submitted_at: i64,
+}
+
+impl EvidenceEntry {
+    /// Serialized size of an entry holding `data_len` bytes
+    pub fn space(data_len: usize) -> usize {
+        // 1 — kind (enum), 4+N — data (Vec<u8>), 32 — submitter (Pubkey), 8 — submitted_at (i64)
+        1 + (4 + data_len) + 32 + 8
+    }
+
+    /// Serialized size of the entries built from `args`
+    pub fn space_for(args: &[EvidenceEntryArgs]) -> usize {
+        args.iter().map(|entry| Self::space(entry.data.len())).sum()
+    }
+}
+
+/// Evidence attached to a report, for moderators to check.
+///
+/// Seeded by `[b"evidence", asset]`, one per minted asset. Created by the mint with
+/// the report's initial entries and reallocated as `add_evidence` appends more.
+#[account]
+pub struct Evidence {
+    /// The asset documenting the scam
+    pub asset: Pubkey,
+    pub entries: Vec<EvidenceEntry>,
+    /// Evidence PDA bump
+    pub bump: u8,
+}
+
+impl Evidence {
+    /// Account size for an evidence account holding the entries built from `args`
+    pub fn space_for(args: &[EvidenceEntryArgs]) -> usize {
+        // 8 — discriminator, 32 — asset, 4+N — entries, 1 — bump
+        8 + 32 + (4 + EvidenceEntry::space_for(args)) + 1
+    }
+
+    /// Current serialized size of the account
+    pub fn space(&self) -> usize {
+        8 + 32
+            + (4 + self
+                .entries
+                .iter()
+                .map(|entry| EvidenceEntry::space(entry.data.len()))
+                .sum::<usize>())
+            + 1
+    }
+
+    /// Whether `args` can be appended: every entry well-formed, and the total within
+    /// MAX_EVIDENCE_ENTRIES
+    pub fn can_append(&self, args: &[EvidenceEntryArgs]) -> bool {
+        self.entries.len() + args.len() <= MAX_EVIDENCE_ENTRIES
+            && args.iter().all(EvidenceEntryArgs::is_valid)
+    }
+
+    /// Appends the entries built from `args`, attributed to `submitter`
+    pub fn append(&mut self, args: Vec<EvidenceEntryArgs>, submitter: Pubkey, timestamp: i64) {
+        self.entries
+            .extend(args.into_iter().map(|entry| EvidenceEntry {
+                kind: entry.kind,
+                data: entry.data,
+                submitter,
+                submitted_at: timestamp,
+            }));
+    }
+}
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of beneficiaries a fee schedule can pay
+pub const MAX_FEE_BENEFICIARIES: usize = 8;
+/// Beneficiary weights must add up to this many basis points
+pub const FEE_SCHEDULE_TOTAL_BPS: u16 = 10_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct FeeBeneficiary {
+    /// Wallet (or treasury PDA) receiving this share
+    pub address: Pubkey,
+    /// Share of every mint payment, in basis points
+    pub weight_bps: u16,
+}
+
+/// N-way split of mint payments between the treasuries and partner organisations
+#[account]
+pub struct FeeSchedule {
+    /// Config account this schedule belongs to
+    pub config: Pubkey,
+    /// Who gets paid, in the order their accounts must be passed to the mint handlers
+    pub beneficiaries: Vec<FeeBeneficiary>,
+    /// Index of the beneficiary that receives the rounding remainder
+    pub remainder_index: u8,
+    /// When false, mints fall back to the two-treasury split in config
+    pub active: bool,
+    /// FeeSchedule PDA bump
+    pub bump: u8,
+}
+
+impl FeeSchedule {
+    /// Checks for 1..=MAX_FEE_BENEFICIARIES unique beneficiaries whose weights sum to
+    /// FEE_SCHEDULE_TOTAL_BPS, and a remainder index that points at one of them
+    pub fn is_valid(beneficiaries: &[FeeBeneficiary], remainder_index: u8) -> bool {
+        if beneficiaries.is_empty() || beneficiaries.len() > MAX_FEE_BENEFICIARIES {
+            return false;
+        }
+        if remainder_index as usize >= beneficiaries.len() {
+            return false;
+        }
+        let unique = beneficiaries.iter().enumerate().all(|(i, b)| {
+            !beneficiaries[..i]
+                .iter()
+                .any(|other| other.address == b.address)
+        });
+        let total_bps = beneficiaries
+            .iter()
+            .map(|b| b.weight_bps as u32)
+            .sum::<u32>();
+        unique && total_bps == FEE_SCHEDULE_TOTAL_BPS as u32
+    }
+
+    pub fn weights(&self) -> Vec<u64> {
+        self.beneficiaries
+            .iter()
+            .map(|b| b.weight_bps as u64)
+            .collect()
+    }
+}
+
+impl Space for FeeSchedule {
+    // 8    — Anchor discriminator
+    // 32   — config (Pubkey)
+    // 4+N  — beneficiaries (Vec of 32-byte address + 2-byte weight, up to MAX_FEE_BENEFICIARIES)
+    // 1    — remainder_index (u8)
+    // 1    — active (bool)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8 + 32 + (4 + (32 + 2) * MAX_FEE_BENEFICIARIES) + 1 + 1 + 1;
+}
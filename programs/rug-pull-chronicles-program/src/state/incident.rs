@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+/// One real-world scam event, shared by every asset documenting it.
+///
+/// Seeded by `[b"incident", chain_id (u64 LE), scam_contract]`, so the chain and the
+/// scam contract address form the canonical incident id. Created by the first mint
+/// reporting it; later mints link to it.
+#[account]
+pub struct Incident {
+    /// EIP-155 chain id for EVM chains, 0 for Solana
+    pub chain_id: u64,
+    /// The scam contract (token, pool or program) address, left-padded to 32 bytes
+    pub scam_contract: Pubkey,
+    /// Number of distinct wallets that documented this incident
+    pub reporter_count: u32,
+    /// Asset minted by the first report
+    pub first_asset: Pubkey,
+    /// When the incident was first reported
+    pub first_reported_at: i64,
+    /// When the incident was last reported
+    pub last_reported_at: i64,
+    /// Incident PDA bump
+    pub bump: u8,
+}
+
+impl Space for Incident {
+    // 8   — Anchor discriminator
+    // 8   — chain_id (u64)
+    // 32  — scam_contract (Pubkey)
+    // 4   — reporter_count (u32)
+    // 32  — first_asset (Pubkey)
+    // 16  — first_reported_at and last_reported_at (i64 × 2)
+    // 1   — bump (u8)
+    const INIT_SPACE: usize = 8 + 8 + 32 + 4 + 32 + 16 + 1;
+}
+
+/// Marks that a wallet has documented an incident, so it can't do so twice.
+///
+/// Seeded by `[b"incident_reporter", incident, reporter]`.
+#[account]
+pub struct IncidentReporter {
+    pub incident: Pubkey,
+    pub reporter: Pubkey,
+    /// Asset the reporter minted for the incident
+    pub asset: Pubkey,
+    pub reported_at: i64,
+    /// IncidentReporter PDA bump
+    pub bump: u8,
+}
+
+impl Space for IncidentReporter {
+    // 8   — Anchor discriminator
+    // 96  — incident, reporter and asset (Pubkey × 3)
+    // 8   — reported_at (i64)
+    // 1   — bump (u8)
+    const INIT_SPACE: usize = 8 + (3 * 32) + 8 + 1;
+}
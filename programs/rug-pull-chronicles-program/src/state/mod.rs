@@ -1,9 +1,37 @@
+pub mod accepted_mint;
+pub mod challenge;
 pub mod collection;
 pub mod config;
+pub mod disbursement;
+pub mod evidence;
+pub mod fee_schedule;
+pub mod incident;
+pub mod moderator;
 pub mod rugged_nft;
+pub mod report_stake;
+pub mod safe_destination;
+pub mod scam_report;
+pub mod scammer_profile;
+pub mod taxonomy;
+pub mod verifier;
+pub mod victim_verification;
+pub use accepted_mint::*;
+pub use challenge::*;
 pub use collection::*;
 pub use config::*;
+pub use disbursement::*;
+pub use evidence::*;
+pub use fee_schedule::*;
+pub use incident::*;
+pub use moderator::*;
 pub use rugged_nft::*;
+pub use report_stake::*;
+pub use safe_destination::*;
+pub use scam_report::*;
+pub use scammer_profile::*;
+pub use taxonomy::*;
+pub use verifier::*;
+pub use victim_verification::*;
 
 use anchor_lang::prelude::*;
 
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// A wallet the admin has allowed to approve or reject pending reports.
+///
+/// Seeded by `[b"moderator", authority]`; removing the moderator closes the account.
+#[account]
+pub struct Moderator {
+    /// Key that signs moderation decisions
+    pub authority: Pubkey,
+    /// Number of reports this moderator approved or rejected
+    pub reports_moderated: u64,
+    pub added_at: i64,
+    /// Moderator PDA bump
+    pub bump: u8,
+}
+
+impl Space for Moderator {
+    // 8   — Anchor discriminator
+    // 32  — authority (Pubkey)
+    // 8   — reports_moderated (u64)
+    // 8   — added_at (i64)
+    // 1   — bump (u8)
+    const INIT_SPACE: usize = 8 + 32 + 8 + 8 + 1;
+}
@@ -0,0 +1,159 @@
+use crate::state::scam_report::ReportStatus;
+use anchor_lang::prelude::*;
+
+/// How long a reporter's stake stays locked after minting, leaving room for
+/// moderation and challenges (30 days)
+pub const REPORT_STAKE_LOCK_PERIOD: i64 = 30 * 24 * 60 * 60;
+/// How long after minting a stake can be released when moderators never decided
+/// its report (90 days)
+pub const REPORT_STAKE_PENDING_EXPIRY: i64 = 90 * 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum StakeStatus {
+    /// Held until the lock period ends and the report is approved, or until the
+    /// pending expiry if the report is never moderated
+    Locked,
+    /// Sent to the anti-scam treasury after the report was rejected or retracted
+    Slashed,
+}
+
+/// Escrow for the lamports a reporter staked on a report.
+///
+/// Seeded by `[b"report_stake", asset]`, alongside the asset's `MintTracker`. The
+/// stake is held in this account on top of its rent; releasing it closes the account.
+#[account]
+pub struct ReportStake {
+    pub asset: Pubkey,
+    pub reporter: Pubkey,
+    /// Lamports staked
+    pub amount: u64,
+    pub locked_at: i64,
+    /// Earliest time the stake can be released
+    pub unlock_at: i64,
+    pub status: StakeStatus,
+    /// ReportStake PDA bump
+    pub bump: u8,
+}
+
+impl ReportStake {
+    /// When the stake can be released before counting challenges: after the lock
+    /// period once the report is approved, or at the pending expiry if nobody
+    /// moderated it. `None` once the report is rejected or retracted.
+    pub fn release_at(&self, report_status: ReportStatus) -> Option<i64> {
+        match report_status {
+            ReportStatus::Approved => Some(self.unlock_at),
+            ReportStatus::Pending => {
+                Some(self.locked_at.saturating_add(REPORT_STAKE_PENDING_EXPIRY))
+            }
+            ReportStatus::Rejected | ReportStatus::Retracted => None,
+        }
+    }
+}
+
+impl Space for ReportStake {
+    // 8   — Anchor discriminator
+    // 64  — asset and reporter (Pubkey × 2)
+    // 8   — amount (u64)
+    // 16  — locked_at and unlock_at (i64 × 2)
+    // 1   — status (enum)
+    // 1   — bump (u8)
+    const INIT_SPACE: usize = 8 + (2 * 32) + 8 + 16 + 1 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::challenge::CHALLENGE_RESOLUTION_PERIOD;
+    use crate::state::scam_report::{ScamReport, ScamReportArgs};
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn approved_report() -> ScamReport {
+        let args = ScamReportArgs {
+            scam_year: 2023,
+            usd_amount_stolen_cents: 100_000_000,
+            platform_category: 0,
+            attack_type: 0,
+            chain_id: 0,
+            scam_contract: Pubkey::new_unique(),
+            scammer_addresses: vec![],
+            evidence: vec![],
+        };
+        let mut report = ScamReport::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            args,
+            Pubkey::new_unique(),
+            0,
+            false,
+            255,
+        );
+        report.status = ReportStatus::Approved;
+        report
+    }
+
+    fn stake() -> ReportStake {
+        ReportStake {
+            asset: Pubkey::new_unique(),
+            reporter: Pubkey::new_unique(),
+            amount: 200_000_000,
+            locked_at: 0,
+            unlock_at: REPORT_STAKE_LOCK_PERIOD,
+            status: StakeStatus::Locked,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn release_time_follows_report_status() {
+        let stake = stake();
+        assert_eq!(
+            stake.release_at(ReportStatus::Approved),
+            Some(REPORT_STAKE_LOCK_PERIOD)
+        );
+        assert_eq!(
+            stake.release_at(ReportStatus::Pending),
+            Some(REPORT_STAKE_PENDING_EXPIRY)
+        );
+        assert_eq!(stake.release_at(ReportStatus::Rejected), None);
+        assert_eq!(stake.release_at(ReportStatus::Retracted), None);
+    }
+
+    #[test]
+    fn open_challenge_holds_stake_until_its_deadline() {
+        let mut report = approved_report();
+        let release_at = stake().release_at(report.status).unwrap();
+        assert_eq!(report.stake_held_until(release_at), release_at);
+
+        let deadline = release_at - DAY + CHALLENGE_RESOLUTION_PERIOD;
+        report.add_challenge(deadline).unwrap();
+        assert_eq!(report.stake_held_until(release_at), deadline + 1);
+
+        report.remove_challenge();
+        assert_eq!(report.stake_held_until(release_at), release_at);
+    }
+
+    #[test]
+    fn reopened_challenges_cannot_hold_stake_forever() {
+        let mut report = approved_report();
+        let release_at = stake().release_at(report.status).unwrap();
+
+        // Opened shortly before the stake unlocks, then expired unresolved
+        let first_deadline = release_at - DAY + CHALLENGE_RESOLUTION_PERIOD;
+        report.add_challenge(first_deadline).unwrap();
+        report.remove_challenge();
+
+        // Reopened right after expiring, and again and again
+        let mut now = first_deadline + 1;
+        for _ in 0..10 {
+            report
+                .add_challenge(now + CHALLENGE_RESOLUTION_PERIOD)
+                .unwrap();
+            now += DAY;
+        }
+
+        let held_until = report.stake_held_until(release_at);
+        assert_eq!(held_until, release_at + CHALLENGE_RESOLUTION_PERIOD);
+        assert!(now >= held_until);
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+/// Longest a safe-destination authorization may stay valid, in seconds
+pub const MAX_SAFE_DESTINATION_TTL: i64 = 600;
+
+/// Domain tag at the start of a signed `SafeDestination`
+pub const SAFE_DESTINATION_TAG: &[u8] = b"rpc:safe_destination";
+
+/// Lets a victim mint to a safe wallet instead of the drained one.
+///
+/// The compromised wallet authorizes it either by co-signing the mint or by signing
+/// `SAFE_DESTINATION_TAG`, the program id and the config key followed by the Borsh
+/// serialization of this struct, checked through the Ed25519 program.
+/// It must be the minting wallet, or the compromised address of the victim
+/// attestation given with the mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SafeDestination {
+    /// The drained wallet authorizing the destination
+    pub compromised: Pubkey,
+    /// Safe wallet that will own the minted asset
+    pub destination: Pubkey,
+    /// Unix timestamp after which the authorization can't be used,
+    /// at most MAX_SAFE_DESTINATION_TTL seconds away
+    pub expires_at: i64,
+    /// Single-use value chosen by the compromised wallet; pre-signed authorizations
+    /// consume it
+    pub nonce: u64,
+}
+
+/// Marks a pre-signed safe-destination nonce as used, seeded by
+/// `[b"destination_nonce", compromised, nonce]`, so that each signed
+/// authorization mints at most once
+#[account]
+pub struct DestinationNonce {
+    /// Flag indicating this nonce has been consumed
+    pub is_used: bool,
+}
+
+impl Space for DestinationNonce {
+    // 8 - Anchor discriminator
+    // 1 - is_used (bool)
+    const INIT_SPACE: usize = 8 + 1;
+}
@@ -0,0 +1,375 @@
+use crate::error::RuggedError;
+use crate::state::challenge::CHALLENGE_RESOLUTION_PERIOD;
+use crate::state::evidence::EvidenceEntryArgs;
+use crate::state::taxonomy::{Taxonomy, TaxonomyKind};
+use anchor_lang::prelude::*;
+use mpl_core::types::Attribute;
+
+/// Maximum number of scammer addresses a report can name
+pub const MAX_SCAMMER_ADDRESSES: usize = 5;
+/// Earliest scam year accepted (the year the Bitcoin network launched)
+pub const MIN_SCAM_YEAR: u16 = 2009;
+
+/// Built-in platform categories. They seed the `Taxonomy`, with each variant's
+/// discriminant as its id.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum PlatformCategory {
+    Dex,
+    Cex,
+    Lending,
+    Bridge,
+    NftMarketplace,
+    Launchpad,
+    Wallet,
+    Gaming,
+    SocialMedia,
+    Other,
+}
+
+impl PlatformCategory {
+    pub const ALL: [PlatformCategory; 10] = [
+        PlatformCategory::Dex,
+        PlatformCategory::Cex,
+        PlatformCategory::Lending,
+        PlatformCategory::Bridge,
+        PlatformCategory::NftMarketplace,
+        PlatformCategory::Launchpad,
+        PlatformCategory::Wallet,
+        PlatformCategory::Gaming,
+        PlatformCategory::SocialMedia,
+        PlatformCategory::Other,
+    ];
+
+    /// Canonical label, written to the asset's `platform_category` attribute
+    pub fn label(&self) -> &'static str {
+        match self {
+            PlatformCategory::Dex => "DEX",
+            PlatformCategory::Cex => "CEX",
+            PlatformCategory::Lending => "Lending",
+            PlatformCategory::Bridge => "Bridge",
+            PlatformCategory::NftMarketplace => "NFT Marketplace",
+            PlatformCategory::Launchpad => "Launchpad",
+            PlatformCategory::Wallet => "Wallet",
+            PlatformCategory::Gaming => "Gaming",
+            PlatformCategory::SocialMedia => "Social Media",
+            PlatformCategory::Other => "Other",
+        }
+    }
+}
+
+/// Built-in attack types. They seed the `Taxonomy`, with each variant's
+/// discriminant as its id.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum AttackType {
+    RugPull,
+    Phishing,
+    ApprovalDrain,
+    SmartContractExploit,
+    PonziScheme,
+    FakeAirdrop,
+    Impersonation,
+    SocialEngineering,
+    Other,
+}
+
+impl AttackType {
+    pub const ALL: [AttackType; 9] = [
+        AttackType::RugPull,
+        AttackType::Phishing,
+        AttackType::ApprovalDrain,
+        AttackType::SmartContractExploit,
+        AttackType::PonziScheme,
+        AttackType::FakeAirdrop,
+        AttackType::Impersonation,
+        AttackType::SocialEngineering,
+        AttackType::Other,
+    ];
+
+    /// Canonical label, written to the asset's `type_of_attack` attribute
+    pub fn label(&self) -> &'static str {
+        match self {
+            AttackType::RugPull => "Rug Pull",
+            AttackType::Phishing => "Phishing",
+            AttackType::ApprovalDrain => "Approval Drain",
+            AttackType::SmartContractExploit => "Smart Contract Exploit",
+            AttackType::PonziScheme => "Ponzi Scheme",
+            AttackType::FakeAirdrop => "Fake Airdrop",
+            AttackType::Impersonation => "Impersonation",
+            AttackType::SocialEngineering => "Social Engineering",
+            AttackType::Other => "Other",
+        }
+    }
+}
+
+/// Where a report is in moderation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ReportStatus {
+    /// Newly minted; the asset stays frozen until a moderator decides
+    Pending,
+    Approved,
+    Rejected,
+    /// Withdrawn after an upheld challenge
+    Retracted,
+}
+
+impl ReportStatus {
+    /// Label written to the asset's `report_status` attribute
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportStatus::Pending => "Pending",
+            ReportStatus::Approved => "Approved",
+            ReportStatus::Rejected => "Rejected",
+            ReportStatus::Retracted => "Retracted",
+        }
+    }
+}
+
+/// Why a moderator rejected a report
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum RejectionReason {
+    Spam,
+    Defamation,
+    InsufficientEvidence,
+    Duplicate,
+    Other,
+}
+
+impl RejectionReason {
+    /// Label written to the asset's `rejection_reason` attribute
+    pub fn label(&self) -> &'static str {
+        match self {
+            RejectionReason::Spam => "Spam",
+            RejectionReason::Defamation => "Defamation",
+            RejectionReason::InsufficientEvidence => "Insufficient Evidence",
+            RejectionReason::Duplicate => "Duplicate",
+            RejectionReason::Other => "Other",
+        }
+    }
+}
+
+/// Typed scam details supplied by the mint handlers' callers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ScamReportArgs {
+    /// Year the scam happened
+    pub scam_year: u16,
+    /// Amount stolen, in US cents
+    pub usd_amount_stolen_cents: u64,
+    /// Taxonomy id of the platform category
+    pub platform_category: u16,
+    /// Taxonomy id of the attack type
+    pub attack_type: u16,
+    /// Chain the scam happened on: the EIP-155 chain id for EVM chains, 0 for Solana
+    pub chain_id: u64,
+    /// The scam contract (token, pool or program) address, left-padded to 32 bytes.
+    /// Together with `chain_id` it identifies the incident.
+    pub scam_contract: Pubkey,
+    /// Addresses used by the scammer. Addresses shorter than 32 bytes (such as EVM
+    /// addresses) are left-padded with zeros.
+    pub scammer_addresses: Vec<Pubkey>,
+    /// Initial evidence, stored in the asset's `Evidence` account
+    pub evidence: Vec<EvidenceEntryArgs>,
+}
+
+impl ScamReportArgs {
+    /// Checks the year against MIN_SCAM_YEAR and `current_year`, that the scam
+    /// contract is set, and that the scammer addresses are non-default, unique and at
+    /// most MAX_SCAMMER_ADDRESSES
+    pub fn is_valid(&self, current_year: u16) -> bool {
+        if self.scam_year < MIN_SCAM_YEAR || self.scam_year > current_year {
+            return false;
+        }
+        if self.scam_contract == Pubkey::default() {
+            return false;
+        }
+        if self.scammer_addresses.len() > MAX_SCAMMER_ADDRESSES {
+            return false;
+        }
+        self.scammer_addresses
+            .iter()
+            .enumerate()
+            .all(|(i, address)| {
+                *address != Pubkey::default() && !self.scammer_addresses[..i].contains(address)
+            })
+    }
+
+    /// Asset attributes derived from the typed report, under the keys the
+    /// collection has always used. Fails if the platform category or attack type
+    /// isn't a usable taxonomy entry.
+    pub fn attributes(&self, taxonomy: &Taxonomy) -> Result<Vec<Attribute>> {
+        let platform_category = taxonomy
+            .label(TaxonomyKind::PlatformCategory, self.platform_category)
+            .ok_or(RuggedError::InvalidTraits)?;
+        let attack_type = taxonomy
+            .label(TaxonomyKind::AttackType, self.attack_type)
+            .ok_or(RuggedError::InvalidTraits)?;
+
+        Ok(vec![
+            Attribute {
+                key: "scam_year".to_string(),
+                value: self.scam_year.to_string(),
+            },
+            Attribute {
+                key: "usd_amount_stolen".to_string(),
+                value: format!(
+                    "{}.{:02}",
+                    self.usd_amount_stolen_cents / 100,
+                    self.usd_amount_stolen_cents % 100
+                ),
+            },
+            Attribute {
+                key: "platform_category".to_string(),
+                value: platform_category.to_string(),
+            },
+            Attribute {
+                key: "type_of_attack".to_string(),
+                value: attack_type.to_string(),
+            },
+            Attribute {
+                key: "chain_id".to_string(),
+                value: self.chain_id.to_string(),
+            },
+        ])
+    }
+}
+
+/// Structured, queryable record of the scam documented by an asset.
+///
+/// Seeded by `[b"scam_report", asset]`, one per minted asset.
+#[account]
+pub struct ScamReport {
+    /// The asset documenting the scam
+    pub asset: Pubkey,
+    /// Wallet that minted the asset
+    pub reporter: Pubkey,
+    pub scam_year: u16,
+    /// Amount stolen, in US cents
+    pub usd_amount_stolen_cents: u64,
+    /// Taxonomy id of the platform category
+    pub platform_category: u16,
+    /// Taxonomy id of the attack type
+    pub attack_type: u16,
+    /// EIP-155 chain id for EVM chains, 0 for Solana
+    pub chain_id: u64,
+    /// The scam contract address, left-padded to 32 bytes
+    pub scam_contract: Pubkey,
+    /// Incident PDA shared by every report of the same scam
+    pub incident: Pubkey,
+    /// Addresses used by the scammer, left-padded to 32 bytes
+    pub scammer_addresses: Vec<Pubkey>,
+    pub created_at: i64,
+    /// Whether the reporter was a verified victim when minting
+    pub verified: bool,
+    pub status: ReportStatus,
+    /// Moderator PDA that approved or rejected the report (default while pending)
+    pub moderator: Pubkey,
+    /// When the report was approved or rejected (0 while pending)
+    pub moderated_at: i64,
+    /// Set when the report is rejected
+    pub rejection_reason: Option<RejectionReason>,
+    /// Number of challenges against the report still open
+    pub open_challenges: u32,
+    /// Latest resolution deadline of the challenges opened against the report
+    pub challenge_deadline: i64,
+    /// ScamReport PDA bump
+    pub bump: u8,
+}
+
+impl ScamReport {
+    pub fn new(
+        asset: Pubkey,
+        reporter: Pubkey,
+        args: ScamReportArgs,
+        incident: Pubkey,
+        created_at: i64,
+        verified: bool,
+        bump: u8,
+    ) -> Self {
+        Self {
+            asset,
+            reporter,
+            scam_year: args.scam_year,
+            usd_amount_stolen_cents: args.usd_amount_stolen_cents,
+            platform_category: args.platform_category,
+            attack_type: args.attack_type,
+            chain_id: args.chain_id,
+            scam_contract: args.scam_contract,
+            incident,
+            scammer_addresses: args.scammer_addresses,
+            created_at,
+            verified,
+            status: ReportStatus::Pending,
+            moderator: Pubkey::default(),
+            moderated_at: 0,
+            rejection_reason: None,
+            open_challenges: 0,
+            challenge_deadline: 0,
+            bump,
+        }
+    }
+
+    /// Counts a newly opened challenge that can be resolved until `resolution_deadline`
+    pub fn add_challenge(&mut self, resolution_deadline: i64) -> Result<()> {
+        self.open_challenges = self
+            .open_challenges
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        self.challenge_deadline = self.challenge_deadline.max(resolution_deadline);
+        Ok(())
+    }
+
+    /// Stops counting a challenge once it is resolved or expired
+    pub fn remove_challenge(&mut self) {
+        self.open_challenges = self.open_challenges.saturating_sub(1);
+    }
+
+    /// Earliest time a stake normally releasable at `release_at` can be released,
+    /// given the challenges against the report. Open challenges can still get the
+    /// stake slashed, so they hold it, but only until their resolution deadline has
+    /// passed and at most one resolution period past `release_at`; reopening
+    /// challenges can't hold it forever.
+    pub fn stake_held_until(&self, release_at: i64) -> i64 {
+        if self.open_challenges == 0 {
+            return release_at;
+        }
+        let challenges_over = self.challenge_deadline.saturating_add(1);
+        let cap = release_at.saturating_add(CHALLENGE_RESOLUTION_PERIOD);
+        release_at.max(challenges_over.min(cap))
+    }
+}
+
+impl Space for ScamReport {
+    // 8    — Anchor discriminator
+    // 64   — asset and reporter (Pubkey × 2)
+    // 2    — scam_year (u16)
+    // 8    — usd_amount_stolen_cents (u64)
+    // 4    — platform_category and attack_type (u16 taxonomy ids × 2)
+    // 8    — chain_id (u64)
+    // 64   — scam_contract and incident (Pubkey × 2)
+    // 4+N  — scammer_addresses (Vec<Pubkey>, up to MAX_SCAMMER_ADDRESSES)
+    // 8    — created_at (i64)
+    // 1    — verified (bool)
+    // 1    — status (enum)
+    // 32   — moderator (Pubkey)
+    // 8    — moderated_at (i64)
+    // 2    — rejection_reason (Option<enum>)
+    // 4    — open_challenges (u32)
+    // 8    — challenge_deadline (i64)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8
+        + (2 * 32)
+        + 2
+        + 8
+        + 4
+        + 8
+        + (2 * 32)
+        + (4 + 32 * MAX_SCAMMER_ADDRESSES)
+        + 8
+        + 1
+        + 1
+        + 32
+        + 8
+        + 2
+        + 4
+        + 8
+        + 1;
+}
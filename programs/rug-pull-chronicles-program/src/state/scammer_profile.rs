@@ -0,0 +1,131 @@
+use crate::state::ScamReport;
+use anchor_lang::prelude::*;
+
+/// Maximum number of most recent assets linked from a scammer profile
+pub const MAX_LINKED_ASSETS: usize = 16;
+
+/// Aggregated reports about one scammer address.
+///
+/// Seeded by `[b"scammer", chain_id (u64 LE), address]`. Created or updated by the
+/// mint handlers for every scammer address a report names, and updated again when
+/// the report is approved or rejected.
+#[account]
+pub struct ScammerProfile {
+    /// EIP-155 chain id for EVM chains, 0 for Solana
+    pub chain_id: u64,
+    /// The scammer's address, left-padded to 32 bytes
+    pub address: Pubkey,
+    /// Number of reports naming this address
+    pub report_count: u64,
+    /// Number of those reports made by verified victims
+    pub verified_report_count: u64,
+    /// Number of those reports approved by a moderator
+    pub approved_report_count: u64,
+    /// Sum of the USD amounts stolen across those reports, in cents
+    pub total_usd_stolen_cents: u64,
+    /// When the address was first reported
+    pub first_seen: i64,
+    /// When the address was last reported
+    pub last_seen: i64,
+    /// Most recent assets documenting this scammer, oldest first
+    pub linked_assets: Vec<Pubkey>,
+    /// ScammerProfile PDA bump
+    pub bump: u8,
+}
+
+impl ScammerProfile {
+    /// Counts a new report about this scammer
+    pub fn record_report(
+        &mut self,
+        asset: Pubkey,
+        usd_amount_stolen_cents: u64,
+        verified: bool,
+        timestamp: i64,
+    ) -> Result<()> {
+        self.report_count = self
+            .report_count
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        if verified {
+            self.verified_report_count = self
+                .verified_report_count
+                .checked_add(1)
+                .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        }
+        // Saturate rather than fail: one absurd report shouldn't block every later one
+        self.total_usd_stolen_cents = self
+            .total_usd_stolen_cents
+            .saturating_add(usd_amount_stolen_cents);
+        self.last_seen = timestamp;
+
+        if self.linked_assets.len() == MAX_LINKED_ASSETS {
+            self.linked_assets.remove(0);
+        }
+        self.linked_assets.push(asset);
+
+        Ok(())
+    }
+
+    /// Counts a moderator's approval of a report already recorded
+    pub fn approve_report(&mut self) -> Result<()> {
+        self.approved_report_count = self
+            .approved_report_count
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Takes back a report counted by `record_report`, once it is rejected or
+    /// retracted, along with its approval if it had one
+    pub fn remove_report(&mut self, report: &ScamReport, approved: bool) {
+        self.report_count = self.report_count.saturating_sub(1);
+        if report.verified {
+            self.verified_report_count = self.verified_report_count.saturating_sub(1);
+        }
+        if approved {
+            self.approved_report_count = self.approved_report_count.saturating_sub(1);
+        }
+        self.total_usd_stolen_cents = self
+            .total_usd_stolen_cents
+            .saturating_sub(report.usd_amount_stolen_cents);
+        self.linked_assets.retain(|linked| *linked != report.asset);
+    }
+}
+
+impl Space for ScammerProfile {
+    // 8    — Anchor discriminator
+    // 8    — chain_id (u64)
+    // 32   — address (Pubkey)
+    // 32   — report_count, verified_report_count, approved_report_count and
+    //        total_usd_stolen_cents (u64 × 4)
+    // 16   — first_seen and last_seen (i64 × 2)
+    // 4+N  — linked_assets (Vec<Pubkey>, up to MAX_LINKED_ASSETS)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8 + 8 + 32 + 32 + 16 + (4 + 32 * MAX_LINKED_ASSETS) + 1;
+}
+
+/// What `check_address` returns to callers, via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AddressRiskSummary {
+    /// Whether an approved report, or a report by a verified victim, names the address
+    pub flagged: bool,
+    pub report_count: u64,
+    /// Reports made by verified victims
+    pub verified_report_count: u64,
+    /// Reports approved by a moderator
+    pub approved_report_count: u64,
+    /// Unix timestamp of the latest report (0 if never reported)
+    pub last_report_at: i64,
+}
+
+impl From<&ScammerProfile> for AddressRiskSummary {
+    fn from(profile: &ScammerProfile) -> Self {
+        Self {
+            flagged: profile.approved_report_count > 0 || profile.verified_report_count > 0,
+            report_count: profile.report_count,
+            verified_report_count: profile.verified_report_count,
+            approved_report_count: profile.approved_report_count,
+            last_report_at: profile.last_seen,
+        }
+    }
+}
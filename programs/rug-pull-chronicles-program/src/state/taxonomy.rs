@@ -0,0 +1,128 @@
+use crate::state::scam_report::{AttackType, PlatformCategory};
+use anchor_lang::prelude::*;
+
+/// Maximum label length (in bytes) of a taxonomy entry
+pub const MAX_TAXONOMY_LABEL_LEN: usize = 32;
+/// Maximum number of entries per taxonomy list
+pub const MAX_TAXONOMY_ENTRIES: usize = 255;
+
+/// Which taxonomy list an entry belongs to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum TaxonomyKind {
+    PlatformCategory,
+    AttackType,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct TaxonomyEntry {
+    /// Numeric id passed to the mint instructions; equal to the entry's index
+    pub id: u16,
+    /// Canonical label written to asset attributes
+    pub label: String,
+    /// Deprecated entries stay readable for existing reports but can't be used for new ones
+    pub deprecated: bool,
+}
+
+impl TaxonomyEntry {
+    /// Serialized size of an entry with the given label
+    pub fn space(label: &str) -> usize {
+        // 2 — id (u16), 4+N — label (String), 1 — deprecated (bool)
+        2 + (4 + label.len()) + 1
+    }
+}
+
+/// Canonical platform categories and attack types that reports refer to by id.
+///
+/// Seeded by `[b"taxonomy", config]`. Created with the built-in `PlatformCategory`
+/// and `AttackType` variants, whose ids are their discriminants, and extended by
+/// the admin. The account is reallocated as entries are added.
+#[account]
+pub struct Taxonomy {
+    /// Config account this taxonomy belongs to
+    pub config: Pubkey,
+    pub platform_categories: Vec<TaxonomyEntry>,
+    pub attack_types: Vec<TaxonomyEntry>,
+    /// Taxonomy PDA bump
+    pub bump: u8,
+}
+
+impl Taxonomy {
+    /// Entries for the built-in platform categories
+    pub fn default_platform_categories() -> Vec<TaxonomyEntry> {
+        Self::entries(PlatformCategory::ALL.iter().map(|c| c.label()))
+    }
+
+    /// Entries for the built-in attack types
+    pub fn default_attack_types() -> Vec<TaxonomyEntry> {
+        Self::entries(AttackType::ALL.iter().map(|a| a.label()))
+    }
+
+    fn entries<'a>(labels: impl Iterator<Item = &'a str>) -> Vec<TaxonomyEntry> {
+        labels
+            .enumerate()
+            .map(|(id, label)| TaxonomyEntry {
+                id: id as u16,
+                label: label.to_string(),
+                deprecated: false,
+            })
+            .collect()
+    }
+
+    /// Space needed for the built-in entries
+    pub fn default_space() -> usize {
+        Self::space_for(
+            &Self::default_platform_categories(),
+            &Self::default_attack_types(),
+        )
+    }
+
+    /// Space currently used by the account's entries
+    pub fn space(&self) -> usize {
+        Self::space_for(&self.platform_categories, &self.attack_types)
+    }
+
+    fn space_for(platform_categories: &[TaxonomyEntry], attack_types: &[TaxonomyEntry]) -> usize {
+        let entries_space = |entries: &[TaxonomyEntry]| {
+            4 + entries
+                .iter()
+                .map(|e| TaxonomyEntry::space(&e.label))
+                .sum::<usize>()
+        };
+        // 8 — discriminator, 32 — config, 1 — bump
+        8 + 32 + entries_space(platform_categories) + entries_space(attack_types) + 1
+    }
+
+    pub fn list(&self, kind: TaxonomyKind) -> &Vec<TaxonomyEntry> {
+        match kind {
+            TaxonomyKind::PlatformCategory => &self.platform_categories,
+            TaxonomyKind::AttackType => &self.attack_types,
+        }
+    }
+
+    pub fn list_mut(&mut self, kind: TaxonomyKind) -> &mut Vec<TaxonomyEntry> {
+        match kind {
+            TaxonomyKind::PlatformCategory => &mut self.platform_categories,
+            TaxonomyKind::AttackType => &mut self.attack_types,
+        }
+    }
+
+    /// Label of a usable (existing, non-deprecated) entry
+    pub fn label(&self, kind: TaxonomyKind, id: u16) -> Option<&str> {
+        self.list(kind)
+            .get(id as usize)
+            .filter(|entry| !entry.deprecated)
+            .map(|entry| entry.label.as_str())
+    }
+
+    /// Checks that a new label is non-empty, fits, and doesn't duplicate an existing
+    /// label of the same kind, ignoring ASCII case
+    pub fn is_valid_new_label(&self, kind: TaxonomyKind, label: &str) -> bool {
+        let list = self.list(kind);
+        !label.trim().is_empty()
+            && label.len() <= MAX_TAXONOMY_LABEL_LEN
+            && list.len() < MAX_TAXONOMY_ENTRIES
+            && !list
+                .iter()
+                .any(|entry| entry.label.trim().eq_ignore_ascii_case(label.trim()))
+    }
+}
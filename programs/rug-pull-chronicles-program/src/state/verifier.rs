@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+/// Maximum display name length (in bytes) of a verifier
+pub const MAX_VERIFIER_NAME_LEN: usize = 32;
+/// Scope bit allowing a verifier to vouch for scammed collection minters.
+/// Bit 0 is unassigned: standard mints don't need verification.
+pub const VERIFIER_SCOPE_SCAMMED: u8 = 1 << 1;
+/// Every scope bit currently defined
+pub const VERIFIER_SCOPE_ALL: u8 = VERIFIER_SCOPE_SCAMMED;
+
+/// A key registered by the admin to verify scam victims.
+///
+/// Seeded by `[b"verifier", authority]`. Suspending or removing a verifier revokes
+/// every `VictimVerification` it has issued, since mints check the issuing verifier.
+#[account]
+pub struct Verifier {
+    /// Key that signs verifications
+    pub authority: Pubkey,
+    /// Display name, e.g. the partner organisation
+    pub name: String,
+    /// Bitflags of the collections this verifier may verify for (VERIFIER_SCOPE_*)
+    pub scope: u8,
+    /// Suspended verifiers can't verify, and their verifications aren't honoured
+    pub active: bool,
+    /// Number of victim verifications issued
+    pub verifications_issued: u64,
+    /// Unix timestamp of registration. Verifications issued before it (by an earlier,
+    /// removed registration of the same key) aren't honoured.
+    pub registered_at: i64,
+    /// Verifier PDA bump
+    pub bump: u8,
+}
+
+impl Verifier {
+    /// Whether the verifier is active and its scope covers `scope`
+    pub fn can_verify(&self, scope: u8) -> bool {
+        self.active && self.scope & scope == scope
+    }
+
+    /// Checks for a non-empty scope made of known bits and a name that fits
+    pub fn is_valid(name: &str, scope: u8) -> bool {
+        name.len() <= MAX_VERIFIER_NAME_LEN && scope != 0 && scope & !VERIFIER_SCOPE_ALL == 0
+    }
+}
+
+impl Space for Verifier {
+    // 8    — Anchor discriminator
+    // 32   — authority (Pubkey)
+    // 4+N  — name (String, up to MAX_VERIFIER_NAME_LEN bytes)
+    // 1    — scope (u8)
+    // 1    — active (bool)
+    // 8    — verifications_issued (u64)
+    // 8    — registered_at (i64)
+    // 1    — bump (u8)
+    const INIT_SPACE: usize = 8 + 32 + (4 + MAX_VERIFIER_NAME_LEN) + 1 + 1 + 8 + 8 + 1;
+}
@@ -0,0 +1,75 @@
+use crate::state::verifier::{Verifier, VERIFIER_SCOPE_SCAMMED};
+use anchor_lang::prelude::*;
+
+/// Proof that a wallet belongs to a verified scam victim.
+///
+/// Seeded by `[VERIFICATION_SEED, wallet]`, so there is at most one record per wallet.
+/// The admin or the issuing verifier closes it with `revoke_victim_verification`, after
+/// which the wallet can be verified again.
+/// With the `scam_verification` feature on, `mint_scammed_nft` requires this record
+/// for the minting wallet, along with the verifier that issued it.
+#[account]
+pub struct VictimVerification {
+    /// The verified victim's wallet
+    pub wallet: Pubkey,
+    /// Verifier PDA that issued the verification
+    pub verifier: Pubkey,
+    /// Verifier key that signed the verification
+    pub verified_by: Pubkey,
+    /// Unix timestamp of the verification
+    pub verified_at: i64,
+    /// VictimVerification PDA bump
+    pub bump: u8,
+}
+
+impl VictimVerification {
+    /// Whether the record is still honoured: its issuing verifier must be active,
+    /// scoped for the scammed collection, and registered when the record was issued
+    pub fn is_honoured_by(&self, verifier_key: &Pubkey, verifier: &Verifier) -> bool {
+        self.verifier == *verifier_key
+            && verifier.can_verify(VERIFIER_SCOPE_SCAMMED)
+            && self.verified_at >= verifier.registered_at
+    }
+}
+
+impl Space for VictimVerification {
+    // 8   — Anchor discriminator
+    // 96  — wallet, verifier and verified_by (Pubkey × 3)
+    // 8   — verified_at (i64)
+    // 1   — bump (u8)
+    const INIT_SPACE: usize = 8 + (3 * 32) + 8 + 1;
+}
+
+/// Domain tag at the start of a signed `VictimAttestation`
+pub const VICTIM_ATTESTATION_TAG: &[u8] = b"rpc:victim_attestation";
+
+/// Off-chain verification of a victim, signed by a registered verifier's key and
+/// checked through the Ed25519 program instead of a `verify_victim` transaction.
+/// The signed message is `VICTIM_ATTESTATION_TAG`, the program id and the config key,
+/// followed by the Borsh serialization of this struct.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VictimAttestation {
+    /// The victim's wallet, which must be the minting wallet
+    pub victim: Pubkey,
+    /// The address the victim lost funds from
+    pub compromised_address: Pubkey,
+    /// Unix timestamp after which the attestation can't be used
+    pub expires_at: i64,
+    /// Single-use value chosen by the verifier
+    pub nonce: u64,
+}
+
+/// Marks a verifier's attestation nonce as used, seeded by
+/// `[b"attestation_nonce", verifier authority, nonce]`, so that each signed
+/// attestation mints at most once
+#[account]
+pub struct AttestationNonce {
+    /// Flag indicating this nonce has been consumed
+    pub is_used: bool,
+}
+
+impl Space for AttestationNonce {
+    // 8 - Anchor discriminator
+    // 1 - is_used (bool)
+    const INIT_SPACE: usize = 8 + 1;
+}
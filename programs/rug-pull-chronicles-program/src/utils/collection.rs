@@ -0,0 +1,35 @@
+use crate::error::RuggedError;
+use anchor_lang::prelude::*;
+use mpl_core::{
+    accounts::BaseCollectionV1,
+    fetch_collection_plugin,
+    types::{Key, MasterEdition, PluginType},
+};
+
+/// Reads an mpl-core collection and checks that the program's `upd_auth` PDA
+/// is its update authority, so the program can mint into it.
+pub fn load_program_collection(
+    collection: &AccountInfo,
+    update_authority: &Pubkey,
+) -> Result<BaseCollectionV1> {
+    require_keys_eq!(
+        *collection.owner,
+        mpl_core::ID,
+        RuggedError::InvalidCollection
+    );
+    let base = BaseCollectionV1::try_from(collection)
+        .map_err(|_| error!(RuggedError::InvalidCollection))?;
+    require!(
+        base.key == Key::CollectionV1 && base.update_authority == *update_authority,
+        RuggedError::InvalidCollection
+    );
+
+    Ok(base)
+}
+
+/// Max supply set by the collection's MasterEdition plugin, if it has one
+pub fn collection_max_supply(collection: &AccountInfo) -> Option<u32> {
+    fetch_collection_plugin::<MasterEdition>(collection, PluginType::MasterEdition)
+        .ok()
+        .and_then(|(_, master_edition, _)| master_edition.max_supply)
+}
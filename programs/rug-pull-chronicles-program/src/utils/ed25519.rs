@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+
+/// Start of the signature offsets in Ed25519 program instruction data,
+/// after the signature count (u8) and a padding byte
+const SIGNATURE_OFFSETS_START: usize = 2;
+/// Size of one offsets entry: seven u16 fields
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+/// Instruction index the Ed25519 program uses for "this instruction"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Builds the bytes an off-chain signer signs for a message of type `tag`: the tag,
+/// this program's id and the config the message is meant for, then the Borsh
+/// encoding of `payload`. Binding all three keeps a signature from being replayed
+/// against another deployment or config, or passed off as another message type.
+pub fn signed_message<T: AnchorSerialize>(
+    tag: &[u8],
+    config: &Pubkey,
+    payload: &T,
+) -> Result<Vec<u8>> {
+    let mut message = [tag, crate::ID.as_ref(), config.as_ref()].concat();
+    payload.serialize(&mut message)?;
+    Ok(message)
+}
+
+/// Returns whether an Ed25519 program instruction earlier in the transaction
+/// verified a signature by `signer` over exactly `message`.
+///
+/// The Ed25519 program fails the whole transaction if any of its signatures is bad,
+/// so by the time this runs the signatures themselves are known to be valid; what's
+/// left to check is that one of them was made by the expected key over the expected
+/// message.
+pub fn has_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<bool> {
+    let current_index = load_current_index_checked(instructions_sysvar)? as usize;
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index, instructions_sysvar)?;
+        if instruction.program_id == ed25519_program::ID
+            && verifies(&instruction.data, signer, message)
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Whether Ed25519 program instruction data checks a signature by `signer` over `message`
+fn verifies(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    let Some(&count) = data.first() else {
+        return false;
+    };
+
+    (0..count as usize).any(|i| {
+        let start = SIGNATURE_OFFSETS_START + i * SIGNATURE_OFFSETS_LEN;
+        let Some(offsets) = data.get(start..start + SIGNATURE_OFFSETS_LEN) else {
+            return false;
+        };
+        let field = |n: usize| u16::from_le_bytes([offsets[n * 2], offsets[n * 2 + 1]]);
+
+        let signature_instruction = field(1);
+        let pubkey_offset = field(2) as usize;
+        let pubkey_instruction = field(3);
+        let message_offset = field(4) as usize;
+        let message_size = field(5) as usize;
+        let message_instruction = field(6);
+
+        // The key, signature and message must all live in the Ed25519 instruction itself,
+        // otherwise the offsets could point the check at some other instruction's data
+        signature_instruction == CURRENT_INSTRUCTION
+            && pubkey_instruction == CURRENT_INSTRUCTION
+            && message_instruction == CURRENT_INSTRUCTION
+            && data.get(pubkey_offset..pubkey_offset + 32) == Some(signer.as_ref())
+            && data.get(message_offset..message_offset + message_size) == Some(message)
+    })
+}
@@ -0,0 +1,119 @@
+use crate::error::CustomError;
+use anchor_lang::prelude::*;
+
+/// Splits `total` between beneficiaries in proportion to `weights`.
+///
+/// Each share is rounded down, and whatever integer division leaves over is added
+/// to the share at `remainder_index`, so the returned shares always sum to `total`
+/// exactly. Weights can be percentages, basis points or any other unit.
+pub fn split_by_weights(total: u64, weights: &[u64], remainder_index: usize) -> Result<Vec<u64>> {
+    require!(
+        remainder_index < weights.len(),
+        CustomError::InvalidFeeDistribution
+    );
+
+    let total_weight = weights
+        .iter()
+        .try_fold(0u128, |acc, weight| acc.checked_add(*weight as u128))
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    require!(total_weight > 0, CustomError::InvalidFeeDistribution);
+
+    // u128 intermediates cannot overflow: u64::MAX * u64::MAX < u128::MAX
+    let mut shares = weights
+        .iter()
+        .map(|weight| (total as u128 * *weight as u128 / total_weight) as u64)
+        .collect::<Vec<u64>>();
+
+    let distributed = shares
+        .iter()
+        .try_fold(0u64, |acc, share| acc.checked_add(*share))
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    let remainder = total
+        .checked_sub(distributed)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    shares[remainder_index] = shares[remainder_index]
+        .checked_add(remainder)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+
+    Ok(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_evenly_divisible_amounts_exactly() {
+        assert_eq!(
+            split_by_weights(10_000, &[60, 40], 1).unwrap(),
+            vec![6_000, 4_000]
+        );
+    }
+
+    #[test]
+    fn gives_dust_to_the_remainder_side() {
+        // 101 * 60 / 100 = 60.6 and 101 * 40 / 100 = 40.4, so one lamport is left over
+        assert_eq!(split_by_weights(101, &[60, 40], 0).unwrap(), vec![61, 40]);
+        assert_eq!(split_by_weights(101, &[60, 40], 1).unwrap(), vec![60, 41]);
+    }
+
+    #[test]
+    fn supports_n_way_splits() {
+        let shares = split_by_weights(1_000, &[3_334, 3_333, 3_333], 2).unwrap();
+        assert_eq!(shares.iter().sum::<u64>(), 1_000);
+        assert_eq!(shares, vec![333, 333, 334]);
+    }
+
+    #[test]
+    fn handles_zero_weights_and_extreme_totals() {
+        assert_eq!(split_by_weights(7, &[0, 100], 0).unwrap(), vec![0, 7]);
+        let shares = split_by_weights(u64::MAX, &[1, 1, 1], 0).unwrap();
+        assert_eq!(shares.iter().map(|s| *s as u128).sum::<u128>(), u64::MAX as u128);
+    }
+
+    #[test]
+    fn rejects_invalid_distributions() {
+        assert!(split_by_weights(100, &[], 0).is_err());
+        assert!(split_by_weights(100, &[0, 0], 0).is_err());
+        assert!(split_by_weights(100, &[50, 50], 2).is_err());
+    }
+
+    /// Property: for any total, weights and remainder index, shares sum to the total
+    /// and no share is smaller than its rounded-down proportional amount.
+    #[test]
+    fn conserves_every_lamport() {
+        // Small deterministic xorshift generator so the property test needs no extra crates
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10_000 {
+            let beneficiaries = (next() % 8 + 1) as usize;
+            let weights = (0..beneficiaries)
+                .map(|_| next() % 10_001)
+                .collect::<Vec<u64>>();
+            if weights.iter().all(|w| *w == 0) {
+                continue;
+            }
+            let total = match next() % 3 {
+                0 => next() % 1_000,
+                1 => next() % 1_000_000_000_000,
+                _ => next(),
+            };
+            let remainder_index = (next() % beneficiaries as u64) as usize;
+
+            let shares = split_by_weights(total, &weights, remainder_index).unwrap();
+            let total_weight = weights.iter().map(|w| *w as u128).sum::<u128>();
+
+            assert_eq!(shares.iter().map(|s| *s as u128).sum::<u128>(), total as u128);
+            for (share, weight) in shares.iter().zip(&weights) {
+                assert!(*share as u128 >= total as u128 * *weight as u128 / total_weight);
+            }
+        }
+    }
+}
@@ -1,31 +1,338 @@
-use crate::state::config::Config;
+use crate::error::CustomError;
+use crate::events::{FeeShare, MintPaymentCollected};
+use crate::state::accepted_mint::AcceptedMint;
+use crate::state::collection::CollectionFeeOverrides;
+use crate::state::config::{Config, FeeRemainderSide};
+use crate::state::fee_schedule::FeeSchedule;
+use crate::utils::fee_split::split_by_weights;
 use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
 
-/// Calculates mint fees based on the config settings
-pub fn calculate_mint_fees(config: &Account<Config>) -> Result<(u64, u64)> {
-    // Calculate total fee amount based on basis points (use config minimum payment)
-    let minimum_payment = config.minimum_payment;
-    let fee_rate = config.mint_fee_basis_points as u64;
-    let fee_amount = minimum_payment
-        .checked_mul(fee_rate)
-        .ok_or(ProgramError::ArithmeticOverflow)?
-        .checked_div(10_000)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Calculate split based on percentages
-    let treasury_amount = fee_amount
-        .checked_mul(config.treasury_fee_percent as u64)
-        .ok_or(ProgramError::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    let antiscam_amount = fee_amount
-        .checked_mul(config.antiscam_fee_percent as u64)
-        .ok_or(ProgramError::ArithmeticOverflow)?
-        .checked_div(100)
-        .ok_or(ProgramError::ArithmeticOverflow)?;
-
-    // Remove all fee logging
-
-    Ok((treasury_amount, antiscam_amount))
+/// Splits a mint payment between the treasury and the anti-scam treasury
+/// using the configured percentages, or the collection's override of them.
+/// Rounding dust goes to the configured remainder side, so
+/// `treasury_amount + antiscam_amount == payment_amount`.
+pub fn calculate_mint_fees(
+    config: &Account<Config>,
+    fee_overrides: &CollectionFeeOverrides,
+    payment_amount: u64,
+) -> Result<(u64, u64)> {
+    let remainder_index = match config.fee_remainder_side {
+        FeeRemainderSide::Treasury => 0,
+        FeeRemainderSide::AntiScam => 1,
+    };
+    let (treasury_percent, antiscam_percent) = match fee_overrides.treasury_fee_percent {
+        Some(percent) => (percent, 100 - percent),
+        None => (config.treasury_fee_percent, config.antiscam_fee_percent),
+    };
+    let shares = split_by_weights(
+        payment_amount,
+        &[treasury_percent as u64, antiscam_percent as u64],
+        remainder_index,
+    )?;
+
+    Ok((shares[0], shares[1]))
+}
+
+/// Accounts needed to pay the mint fee with an accepted SPL token
+pub struct TokenFeeAccounts<'a, 'info> {
+    pub payment_mint: &'a InterfaceAccount<'info, Mint>,
+    pub accepted_mint: &'a Account<'info, AcceptedMint>,
+    pub payer_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub treasury_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub antiscam_token_account: &'a InterfaceAccount<'info, TokenAccount>,
+    pub token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> TokenFeeAccounts<'a, 'info> {
+    /// Builds the token payment from the mint handlers' optional accounts.
+    /// Returns `None` (pay in SOL) when no payment mint is given; once a payment
+    /// mint is given, every other token account must be present as well.
+    pub fn from_optional(
+        payment_mint: Option<&'a InterfaceAccount<'info, Mint>>,
+        accepted_mint: Option<&'a Account<'info, AcceptedMint>>,
+        payer_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        treasury_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        antiscam_token_account: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+        token_program: Option<&'a Interface<'info, TokenInterface>>,
+    ) -> Result<Option<Self>> {
+        let Some(payment_mint) = payment_mint else {
+            return Ok(None);
+        };
+
+        match (
+            accepted_mint,
+            payer_token_account,
+            treasury_token_account,
+            antiscam_token_account,
+            token_program,
+        ) {
+            (
+                Some(accepted_mint),
+                Some(payer_token_account),
+                Some(treasury_token_account),
+                Some(antiscam_token_account),
+                Some(token_program),
+            ) => Ok(Some(Self {
+                payment_mint,
+                accepted_mint,
+                payer_token_account,
+                treasury_token_account,
+                antiscam_token_account,
+                token_program,
+            })),
+            _ => err!(CustomError::IncompleteTokenPayment),
+        }
+    }
+
+    /// Checks the payment option against config and the treasuries' token accounts
+    fn validate(&self, config: &Account<Config>) -> Result<()> {
+        let mint = self.payment_mint.key();
+        let token_program = self.token_program.key();
+
+        require!(
+            self.accepted_mint.config == config.key()
+                && self.accepted_mint.mint == mint
+                && self.accepted_mint.token_program == token_program
+                && self.accepted_mint.enabled,
+            CustomError::PaymentMintNotAccepted
+        );
+
+        require_keys_eq!(
+            self.treasury_token_account.key(),
+            get_associated_token_address_with_program_id(&config.treasury, &mint, &token_program),
+            CustomError::InvalidTreasuryTokenAccount
+        );
+        require_keys_eq!(
+            self.antiscam_token_account.key(),
+            get_associated_token_address_with_program_id(
+                &config.antiscam_treasury,
+                &mint,
+                &token_program
+            ),
+            CustomError::InvalidTreasuryTokenAccount
+        );
+
+        Ok(())
+    }
+
+    fn transfer(&self, payer: &Signer<'info>, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: self.payer_token_account.to_account_info(),
+                    mint: self.payment_mint.to_account_info(),
+                    to: to.clone(),
+                    authority: payer.to_account_info(),
+                },
+            ),
+            amount,
+            self.payment_mint.decimals,
+        )
+    }
+}
+
+/// Splits the mint handlers' remaining accounts into the fee beneficiary accounts
+/// required by the active fee schedule and whatever follows them.
+/// Fails if config points at a fee schedule that wasn't passed in.
+pub fn split_beneficiary_accounts<'a, 'info>(
+    config: &Account<Config>,
+    fee_schedule: Option<&Account<FeeSchedule>>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>])> {
+    require_keys_eq!(
+        fee_schedule.map(|s| s.key()).unwrap_or_default(),
+        config.fee_schedule,
+        CustomError::FeeScheduleRequired
+    );
+
+    let count = fee_schedule
+        .filter(|s| s.active)
+        .map_or(0, |s| s.beneficiaries.len());
+    require!(
+        remaining_accounts.len() >= count,
+        CustomError::InvalidFeeBeneficiary
+    );
+
+    Ok(remaining_accounts.split_at(count))
+}
+
+/// Everything the mint handlers need to charge a mint payment
+pub struct MintPayment<'a, 'info> {
+    pub config: &'a Account<'info, Config>,
+    pub payer: &'a Signer<'info>,
+    pub treasury: &'a AccountInfo<'info>,
+    pub antiscam_treasury: &'a AccountInfo<'info>,
+    pub system_program: &'a Program<'info, System>,
+    /// Set when paying in an accepted SPL token instead of SOL
+    pub token_fee: Option<TokenFeeAccounts<'a, 'info>>,
+    /// The config's fee schedule, if one has been set up
+    pub fee_schedule: Option<&'a Account<'info, FeeSchedule>>,
+    /// One account per schedule beneficiary, in schedule order: the beneficiary
+    /// itself for SOL payments, or its token account for the payment mint
+    pub beneficiary_accounts: &'a [AccountInfo<'info>],
+    /// Fee settings of the collection being minted into that replace the config's
+    pub fee_overrides: CollectionFeeOverrides,
+}
+
+impl<'a, 'info> MintPayment<'a, 'info> {
+    /// Charges the user-chosen payment and splits all of it, either between the two
+    /// treasuries in config or between the beneficiaries of an active fee schedule.
+    /// Payments below the payment option's minimum are rejected.
+    pub fn collect(self, asset: Pubkey, payment_amount: u64) -> Result<()> {
+        let minimum_payment = match &self.token_fee {
+            Some(token_fee) => {
+                token_fee.validate(self.config)?;
+                token_fee.accepted_mint.minimum_payment
+            }
+            None => self
+                .fee_overrides
+                .minimum_payment
+                .unwrap_or(self.config.minimum_payment),
+        };
+        require!(
+            payment_amount >= minimum_payment,
+            CustomError::PaymentBelowMinimum
+        );
+
+        let shares = match self.fee_schedule.filter(|s| s.active) {
+            Some(fee_schedule) => self.pay_fee_schedule(fee_schedule, payment_amount)?,
+            None => self.pay_treasuries(payment_amount)?,
+        };
+
+        let paid_to = |recipient: Pubkey| {
+            shares
+                .iter()
+                .filter(|share| share.recipient == recipient)
+                .map(|share| share.amount)
+                .sum::<u64>()
+        };
+
+        emit!(MintPaymentCollected {
+            payer: self.payer.key(),
+            asset,
+            payment_mint: self.token_fee.as_ref().map(|t| t.payment_mint.key()),
+            payment_amount,
+            treasury_amount: paid_to(self.config.treasury),
+            antiscam_amount: paid_to(self.config.antiscam_treasury),
+            shares,
+        });
+
+        Ok(())
+    }
+
+    /// Two-way split between the treasury and the anti-scam treasury
+    fn pay_treasuries(&self, payment_amount: u64) -> Result<Vec<FeeShare>> {
+        let (treasury_amount, antiscam_amount) =
+            calculate_mint_fees(self.config, &self.fee_overrides, payment_amount)?;
+
+        match &self.token_fee {
+            Some(token_fee) => {
+                // Transfer to the treasuries' token accounts
+                token_fee.transfer(
+                    self.payer,
+                    &token_fee.treasury_token_account.to_account_info(),
+                    treasury_amount,
+                )?;
+                token_fee.transfer(
+                    self.payer,
+                    &token_fee.antiscam_token_account.to_account_info(),
+                    antiscam_amount,
+                )?;
+            }
+            None => {
+                // Transfer to main treasury
+                self.transfer_lamports(self.treasury, treasury_amount)?;
+                // Transfer to anti-scam treasury
+                self.transfer_lamports(self.antiscam_treasury, antiscam_amount)?;
+            }
+        }
+
+        Ok(vec![
+            FeeShare {
+                recipient: self.config.treasury,
+                amount: treasury_amount,
+            },
+            FeeShare {
+                recipient: self.config.antiscam_treasury,
+                amount: antiscam_amount,
+            },
+        ])
+    }
+
+    /// N-way split between the fee schedule's beneficiaries
+    fn pay_fee_schedule(
+        &self,
+        fee_schedule: &Account<FeeSchedule>,
+        payment_amount: u64,
+    ) -> Result<Vec<FeeShare>> {
+        let amounts = split_by_weights(
+            payment_amount,
+            &fee_schedule.weights(),
+            fee_schedule.remainder_index as usize,
+        )?;
+        require!(
+            self.beneficiary_accounts.len() == fee_schedule.beneficiaries.len(),
+            CustomError::InvalidFeeBeneficiary
+        );
+
+        let mut shares = Vec::with_capacity(amounts.len());
+        for ((beneficiary, account), amount) in fee_schedule
+            .beneficiaries
+            .iter()
+            .zip(self.beneficiary_accounts)
+            .zip(amounts)
+        {
+            match &self.token_fee {
+                Some(token_fee) => {
+                    // Beneficiaries are paid into their own token account for the payment mint
+                    require_keys_eq!(
+                        *account.owner,
+                        token_fee.token_program.key(),
+                        CustomError::InvalidFeeBeneficiary
+                    );
+                    let token_account =
+                        TokenAccount::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+                    require!(
+                        token_account.mint == token_fee.payment_mint.key()
+                            && token_account.owner == beneficiary.address,
+                        CustomError::InvalidFeeBeneficiary
+                    );
+                    token_fee.transfer(self.payer, account, amount)?;
+                }
+                None => {
+                    require_keys_eq!(
+                        account.key(),
+                        beneficiary.address,
+                        CustomError::InvalidFeeBeneficiary
+                    );
+                    self.transfer_lamports(account, amount)?;
+                }
+            }
+
+            shares.push(FeeShare {
+                recipient: beneficiary.address,
+                amount,
+            });
+        }
+
+        Ok(shares)
+    }
+
+    fn transfer_lamports(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: self.payer.to_account_info(),
+                    to: to.clone(),
+                },
+            ),
+            amount,
+        )
+    }
 }
@@ -0,0 +1,100 @@
+use crate::error::RuggedError;
+use crate::events::IncidentReported;
+use crate::state::{Incident, IncidentReporter, ScamReportArgs};
+use crate::utils::pda::create_pda_account;
+use anchor_lang::prelude::*;
+
+/// Accounts a mint handler uses to link its report to the shared `Incident`
+pub struct IncidentAccounts<'a, 'info> {
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    /// Incident PDA, already checked against the report's seeds
+    pub incident: &'a AccountInfo<'info>,
+    pub incident_bump: u8,
+    /// IncidentReporter PDA for the incident and the reporting wallet
+    pub incident_reporter: &'a AccountInfo<'info>,
+    pub incident_reporter_bump: u8,
+}
+
+impl<'a, 'info> IncidentAccounts<'a, 'info> {
+    /// Creates the incident on its first report, links later ones to it, and fails
+    /// with `DuplicateNFTMint` if `reporter` already documented it.
+    pub fn record(
+        &self,
+        report: &ScamReportArgs,
+        reporter: Pubkey,
+        asset: Pubkey,
+        timestamp: i64,
+    ) -> Result<()> {
+        require!(
+            *self.incident_reporter.owner != crate::ID,
+            RuggedError::DuplicateNFTMint
+        );
+
+        let incident_key = self.incident.key();
+        let chain_id = report.chain_id.to_le_bytes();
+        let mut incident = if *self.incident.owner == crate::ID {
+            Incident::try_deserialize(&mut &self.incident.try_borrow_data()?[..])?
+        } else {
+            // First report of this incident
+            create_pda_account(
+                self.payer,
+                self.incident,
+                self.system_program,
+                Incident::INIT_SPACE,
+                &[
+                    b"incident",
+                    &chain_id,
+                    report.scam_contract.as_ref(),
+                    &[self.incident_bump],
+                ],
+            )?;
+            Incident {
+                chain_id: report.chain_id,
+                scam_contract: report.scam_contract,
+                reporter_count: 0,
+                first_asset: asset,
+                first_reported_at: timestamp,
+                last_reported_at: timestamp,
+                bump: self.incident_bump,
+            }
+        };
+
+        incident.reporter_count = incident
+            .reporter_count
+            .checked_add(1)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        incident.last_reported_at = timestamp;
+        incident.try_serialize(&mut &mut self.incident.try_borrow_mut_data()?[..])?;
+
+        create_pda_account(
+            self.payer,
+            self.incident_reporter,
+            self.system_program,
+            IncidentReporter::INIT_SPACE,
+            &[
+                b"incident_reporter",
+                incident_key.as_ref(),
+                reporter.as_ref(),
+                &[self.incident_reporter_bump],
+            ],
+        )?;
+        IncidentReporter {
+            incident: incident_key,
+            reporter,
+            asset,
+            reported_at: timestamp,
+            bump: self.incident_reporter_bump,
+        }
+        .try_serialize(&mut &mut self.incident_reporter.try_borrow_mut_data()?[..])?;
+
+        emit!(IncidentReported {
+            incident: incident_key,
+            reporter,
+            asset,
+            reporter_count: incident.reporter_count,
+        });
+
+        Ok(())
+    }
+}
@@ -1 +1,12 @@
+pub mod collection;
+pub mod ed25519;
+pub mod fee_split;
 pub mod fees;
+pub mod incident;
+pub mod moderation;
+pub mod pda;
+pub mod report_stake;
+pub mod royalties;
+pub mod scammer_registry;
+pub mod time;
+pub mod treasury;
@@ -0,0 +1,135 @@
+use crate::state::{RejectionReason, ReportStatus};
+use anchor_lang::prelude::*;
+use mpl_core::{
+    fetch_asset_plugin,
+    instructions::{BurnV1CpiBuilder, UpdatePluginV1CpiBuilder},
+    types::{
+        Attribute, Attributes, PermanentBurnDelegate, PermanentFreezeDelegate, Plugin,
+        PluginAuthority, PluginAuthorityPair, PluginType,
+    },
+};
+
+/// Plugins every newly minted asset starts with while its report is pending.
+///
+/// The permanent freeze and burn delegates belong to the `upd_auth` PDA, so only
+/// moderation can thaw or burn the asset. They are permanent because an owner can
+/// revoke a plain `FreezeDelegate` once the asset is thawed, and a report retracted
+/// after approval must still be able to re-freeze it. The burn delegate is only ever
+/// used on a pending report (see `ModeratedAsset::burn`). Being permanent, they leave
+/// the owner free to add their own `FreezeDelegate` with `add_freeze_delegate`, which
+/// `freeze_asset` / `thaw_asset` drive independently of the moderation state.
+pub fn pending_report_plugins(update_authority: Pubkey) -> Vec<PluginAuthorityPair> {
+    let authority = Some(PluginAuthority::Address {
+        address: update_authority,
+    });
+    vec![
+        PluginAuthorityPair {
+            plugin: Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate { frozen: true }),
+            authority: authority.clone(),
+        },
+        PluginAuthorityPair {
+            plugin: Plugin::PermanentBurnDelegate(PermanentBurnDelegate {}),
+            authority,
+        },
+    ]
+}
+
+/// Attribute added at mint time to record the report's moderation status
+pub fn pending_status_attribute() -> Attribute {
+    Attribute {
+        key: "report_status".to_string(),
+        value: ReportStatus::Pending.label().to_string(),
+    }
+}
+
+/// Accounts needed to update a minted asset's plugins as the `upd_auth` PDA
+pub struct ModeratedAsset<'a, 'info> {
+    pub asset: &'a AccountInfo<'info>,
+    pub collection: &'a AccountInfo<'info>,
+    pub update_authority: &'a AccountInfo<'info>,
+    pub update_authority_bump: u8,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub mpl_core_program: &'a AccountInfo<'info>,
+}
+
+impl<'a, 'info> ModeratedAsset<'a, 'info> {
+    /// Freezes or thaws the asset through its permanent freeze delegate
+    pub fn set_frozen(&self, frozen: bool) -> Result<()> {
+        self.update_plugin(Plugin::PermanentFreezeDelegate(PermanentFreezeDelegate {
+            frozen,
+        }))
+    }
+
+    /// Rewrites the `report_status` attribute, and `rejection_reason` when given,
+    /// keeping every other attribute
+    pub fn set_status_attributes(
+        &self,
+        status: ReportStatus,
+        rejection_reason: Option<RejectionReason>,
+    ) -> Result<()> {
+        let (_, mut attributes, _) =
+            fetch_asset_plugin::<Attributes>(self.asset, PluginType::Attributes)
+                .map_err(|_| ErrorCode::AccountDidNotDeserialize)?;
+
+        set_attribute(
+            &mut attributes.attribute_list,
+            "report_status",
+            status.label(),
+        );
+        if let Some(reason) = rejection_reason {
+            set_attribute(
+                &mut attributes.attribute_list,
+                "rejection_reason",
+                reason.label(),
+            );
+        }
+
+        self.update_plugin(Plugin::Attributes(attributes))
+    }
+
+    /// Burns the asset through its permanent burn delegate. Only assets whose report
+    /// was still pending can be burned; approved assets may already be traded.
+    pub fn burn(&self, report_status: ReportStatus) -> Result<()> {
+        require!(
+            report_status == ReportStatus::Pending,
+            crate::error::RuggedError::ReportAlreadyModerated
+        );
+
+        BurnV1CpiBuilder::new(self.mpl_core_program)
+            .asset(self.asset)
+            .collection(Some(self.collection))
+            .authority(Some(self.update_authority))
+            .payer(self.payer)
+            .system_program(Some(self.system_program))
+            .invoke_signed(&[&[b"upd_auth", &[self.update_authority_bump]]])?;
+
+        Ok(())
+    }
+
+    fn update_plugin(&self, plugin: Plugin) -> Result<()> {
+        UpdatePluginV1CpiBuilder::new(self.mpl_core_program)
+            .asset(self.asset)
+            .collection(Some(self.collection))
+            .authority(Some(self.update_authority))
+            .payer(self.payer)
+            .system_program(self.system_program)
+            .plugin(plugin)
+            .invoke_signed(&[&[b"upd_auth", &[self.update_authority_bump]]])?;
+
+        Ok(())
+    }
+}
+
+fn set_attribute(attribute_list: &mut Vec<Attribute>, key: &str, value: &str) {
+    match attribute_list
+        .iter_mut()
+        .find(|attribute| attribute.key == key)
+    {
+        Some(attribute) => attribute.value = value.to_string(),
+        None => attribute_list.push(Attribute {
+            key: key.to_string(),
+            value: value.to_string(),
+        }),
+    }
+}
@@ -0,0 +1,91 @@
+use crate::events::{ReportStakeLocked, ReportStakeSlashed};
+use crate::state::{ReportStake, StakeStatus, REPORT_STAKE_LOCK_PERIOD};
+use crate::utils::pda::create_pda_account;
+use crate::utils::treasury::withdraw_from_treasury;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+/// Accounts a mint handler uses to lock the reporter's stake
+pub struct StakeAccounts<'a, 'info> {
+    pub reporter: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    /// ReportStake PDA, already checked against the asset's seeds
+    pub report_stake: &'a AccountInfo<'info>,
+    pub report_stake_bump: u8,
+}
+
+impl<'a, 'info> StakeAccounts<'a, 'info> {
+    /// Creates the asset's `ReportStake` and moves `amount` lamports into it
+    pub fn lock(&self, asset: Pubkey, amount: u64, timestamp: i64) -> Result<()> {
+        create_pda_account(
+            self.reporter,
+            self.report_stake,
+            self.system_program,
+            ReportStake::INIT_SPACE,
+            &[b"report_stake", asset.as_ref(), &[self.report_stake_bump]],
+        )?;
+        system_program::transfer(
+            CpiContext::new(
+                self.system_program.clone(),
+                Transfer {
+                    from: self.reporter.clone(),
+                    to: self.report_stake.clone(),
+                },
+            ),
+            amount,
+        )?;
+
+        let unlock_at = timestamp
+            .checked_add(REPORT_STAKE_LOCK_PERIOD)
+            .ok_or(crate::error::CustomError::ArithmeticOverflow)?;
+        ReportStake {
+            asset,
+            reporter: self.reporter.key(),
+            amount,
+            locked_at: timestamp,
+            unlock_at,
+            status: StakeStatus::Locked,
+            bump: self.report_stake_bump,
+        }
+        .try_serialize(&mut &mut self.report_stake.try_borrow_mut_data()?[..])?;
+
+        emit!(ReportStakeLocked {
+            asset,
+            reporter: self.reporter.key(),
+            amount,
+            unlock_at,
+        });
+
+        Ok(())
+    }
+}
+
+/// Sends a report's locked stake to the anti-scam treasury.
+///
+/// Does nothing if the reporter didn't stake, so callers can pass the asset's
+/// ReportStake address unconditionally.
+pub fn slash_report_stake(
+    report_stake: &AccountInfo,
+    antiscam_treasury: &AccountInfo,
+) -> Result<()> {
+    if *report_stake.owner != crate::ID {
+        return Ok(());
+    }
+
+    let mut stake = ReportStake::try_deserialize(&mut &report_stake.try_borrow_data()?[..])?;
+    if stake.status != StakeStatus::Locked {
+        return Ok(());
+    }
+
+    withdraw_from_treasury(report_stake, antiscam_treasury, stake.amount)?;
+    stake.status = StakeStatus::Slashed;
+    stake.try_serialize(&mut &mut report_stake.try_borrow_mut_data()?[..])?;
+
+    emit!(ReportStakeSlashed {
+        asset: stake.asset,
+        reporter: stake.reporter,
+        amount: stake.amount,
+    });
+
+    Ok(())
+}
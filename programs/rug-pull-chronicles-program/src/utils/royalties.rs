@@ -0,0 +1,49 @@
+use crate::state::config::Config;
+use anchor_lang::prelude::*;
+use mpl_core::{
+    fetch_collection_plugin,
+    types::{Creator, Plugin, PluginAuthorityPair, PluginType, Royalties, RuleSet},
+};
+
+/// Asset-level Royalties plugin for a scammed-collection asset that sends the
+/// configured share of its secondary royalties to the victim and the rest to the
+/// anti-scam treasury. Returns `None` while victim royalties are switched off.
+///
+/// The collection's rule set is kept, so the override doesn't lift its marketplace
+/// allow or deny list.
+pub fn victim_royalties_plugin(
+    config: &Config,
+    collection: &AccountInfo,
+    victim: Pubkey,
+) -> Option<PluginAuthorityPair> {
+    if config.victim_royalty_basis_points == 0 {
+        return None;
+    }
+
+    let rule_set = fetch_collection_plugin::<Royalties>(collection, PluginType::Royalties)
+        .map(|(_, royalties, _)| royalties.rule_set)
+        .unwrap_or(RuleSet::None);
+
+    // Creators with no share are left out
+    let victim_percent = config.victim_royalty_percent;
+    let creators = [
+        (victim, victim_percent),
+        (config.antiscam_treasury, 100 - victim_percent),
+    ]
+    .into_iter()
+    .filter(|(_, percentage)| *percentage > 0)
+    .map(|(address, percentage)| Creator {
+        address,
+        percentage,
+    })
+    .collect();
+
+    Some(PluginAuthorityPair {
+        plugin: Plugin::Royalties(Royalties {
+            basis_points: config.victim_royalty_basis_points,
+            creators,
+            rule_set,
+        }),
+        authority: None, // Use default authority
+    })
+}
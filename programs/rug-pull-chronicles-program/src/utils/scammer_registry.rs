@@ -0,0 +1,95 @@
+use crate::error::RuggedError;
+use crate::state::{ScamReport, ScamReportArgs, ScammerProfile};
+use crate::utils::pda::create_pda_account;
+use anchor_lang::prelude::*;
+
+/// Creates or updates the `ScammerProfile` of every scammer address in a report.
+///
+/// `profile_accounts` must hold one writable account per scammer address, in the
+/// report's order. Missing profiles are created, paid for by `payer`. `verified`
+/// says whether the reporter is a verified victim.
+pub fn record_scammer_reports<'info>(
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    profile_accounts: &[AccountInfo<'info>],
+    report: &ScamReportArgs,
+    asset: Pubkey,
+    verified: bool,
+    timestamp: i64,
+) -> Result<()> {
+    require!(
+        profile_accounts.len() == report.scammer_addresses.len(),
+        RuggedError::InvalidScammerProfile
+    );
+
+    let chain_id = report.chain_id.to_le_bytes();
+    for (address, account) in report.scammer_addresses.iter().zip(profile_accounts) {
+        let (expected, bump) =
+            Pubkey::find_program_address(&[b"scammer", &chain_id, address.as_ref()], &crate::ID);
+        require_keys_eq!(account.key(), expected, RuggedError::InvalidScammerProfile);
+
+        let mut profile = if *account.owner == crate::ID {
+            ScammerProfile::try_deserialize(&mut &account.try_borrow_data()?[..])?
+        } else {
+            // First report naming this address
+            create_pda_account(
+                payer,
+                account,
+                system_program,
+                ScammerProfile::INIT_SPACE,
+                &[b"scammer", &chain_id, address.as_ref(), &[bump]],
+            )?;
+            ScammerProfile {
+                chain_id: report.chain_id,
+                address: *address,
+                report_count: 0,
+                verified_report_count: 0,
+                approved_report_count: 0,
+                total_usd_stolen_cents: 0,
+                first_seen: timestamp,
+                last_seen: timestamp,
+                linked_assets: Vec::with_capacity(1),
+                bump,
+            }
+        };
+
+        profile.record_report(asset, report.usd_amount_stolen_cents, verified, timestamp)?;
+        profile.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
+
+/// Applies a moderation decision to the `ScammerProfile` of every scammer address a
+/// report names.
+///
+/// `profile_accounts` must hold the writable profiles the report was counted into, in
+/// the report's order.
+pub fn update_scammer_profiles(
+    profile_accounts: &[AccountInfo],
+    report: &ScamReport,
+    mut update: impl FnMut(&mut ScammerProfile) -> Result<()>,
+) -> Result<()> {
+    require!(
+        profile_accounts.len() == report.scammer_addresses.len(),
+        RuggedError::InvalidScammerProfile
+    );
+
+    let chain_id = report.chain_id.to_le_bytes();
+    for (address, account) in report.scammer_addresses.iter().zip(profile_accounts) {
+        let (expected, _) =
+            Pubkey::find_program_address(&[b"scammer", &chain_id, address.as_ref()], &crate::ID);
+        require_keys_eq!(account.key(), expected, RuggedError::InvalidScammerProfile);
+        require_keys_eq!(
+            *account.owner,
+            crate::ID,
+            RuggedError::InvalidScammerProfile
+        );
+
+        let mut profile = ScammerProfile::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        update(&mut profile)?;
+        profile.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+/// Calendar year of a unix timestamp (UTC)
+pub fn year_from_unix_timestamp(timestamp: i64) -> u16 {
+    // Civil-from-days conversion over 400-year eras, which handles leap years exactly
+    let days = timestamp.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    // Months are counted from March, so January and February belong to the next year
+    let year = year_of_era + era * 400 + if month_index >= 10 { 1 } else { 0 };
+    year as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn starts_at_the_epoch() {
+        assert_eq!(year_from_unix_timestamp(0), 1970);
+        assert_eq!(year_from_unix_timestamp(DAY - 1), 1970);
+    }
+
+    #[test]
+    fn switches_years_at_midnight_on_new_year() {
+        // 1999-12-31T23:59:59Z and 2000-01-01T00:00:00Z
+        assert_eq!(year_from_unix_timestamp(946_684_799), 1999);
+        assert_eq!(year_from_unix_timestamp(946_684_800), 2000);
+        // 2100-12-31T23:59:59Z and 2101-01-01T00:00:00Z
+        assert_eq!(year_from_unix_timestamp(4_133_980_799), 2100);
+        assert_eq!(year_from_unix_timestamp(4_133_980_800), 2101);
+    }
+
+    #[test]
+    fn keeps_leap_days_in_their_year() {
+        // 2000-02-29T00:00:00Z, a leap day in a leap century
+        assert_eq!(year_from_unix_timestamp(951_782_400), 2000);
+        // 2024-02-29T12:00:00Z and 2024-03-01T00:00:00Z
+        assert_eq!(year_from_unix_timestamp(1_709_208_000), 2024);
+        assert_eq!(year_from_unix_timestamp(1_709_251_200), 2024);
+    }
+
+    #[test]
+    fn handles_non_leap_centuries() {
+        // 2100 has no Feb 29: 2100-02-28T23:59:59Z is followed by 2100-03-01T00:00:00Z
+        assert_eq!(year_from_unix_timestamp(4_107_542_399), 2100);
+        assert_eq!(year_from_unix_timestamp(4_107_542_400), 2100);
+    }
+
+    /// Property: every day from the epoch to 2400 falls in the year a simple
+    /// day-counting reference puts it in
+    #[test]
+    fn matches_a_day_counting_reference() {
+        let is_leap = |year: i64| (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+
+        let mut timestamp = 0;
+        for year in 1970..2400 {
+            let days = if is_leap(year) { 366 } else { 365 };
+            for _ in 0..days {
+                assert_eq!(year_from_unix_timestamp(timestamp) as i64, year);
+                assert_eq!(year_from_unix_timestamp(timestamp + DAY - 1) as i64, year);
+                timestamp += DAY;
+            }
+        }
+    }
+}
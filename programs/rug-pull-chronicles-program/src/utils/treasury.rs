@@ -0,0 +1,75 @@
+use crate::error::CustomError;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+/// Moves lamports out of a program-owned treasury PDA.
+///
+/// The treasuries are created by `initialize` as zero-space accounts owned by this
+/// program, so the system program cannot debit them; we adjust lamports directly
+/// instead. The treasury is never allowed to drop below its rent-exempt minimum.
+///
+/// Returns the treasury balance left after the withdrawal.
+pub fn withdraw_from_treasury(
+    treasury: &AccountInfo,
+    recipient: &AccountInfo,
+    amount: u64,
+) -> Result<u64> {
+    require!(amount > 0, CustomError::InvalidWithdrawalAmount);
+
+    let rent_floor = Rent::get()?.minimum_balance(treasury.data_len());
+    let remaining = treasury
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(CustomError::InsufficientTreasuryBalance)?;
+    require!(
+        remaining >= rent_floor,
+        CustomError::InsufficientTreasuryBalance
+    );
+
+    **treasury.try_borrow_mut_lamports()? = remaining;
+    let recipient_balance = recipient
+        .lamports()
+        .checked_add(amount)
+        .ok_or(CustomError::ArithmeticOverflow)?;
+    **recipient.try_borrow_mut_lamports()? = recipient_balance;
+
+    Ok(remaining)
+}
+
+/// Moves SPL tokens out of a treasury PDA's associated token account, signing
+/// for the PDA with `treasury_seeds` (including its bump).
+///
+/// Returns the token balance left in the treasury's token account.
+pub fn withdraw_token_from_treasury<'info>(
+    treasury: &AccountInfo<'info>,
+    treasury_seeds: &[&[u8]],
+    treasury_token_account: &mut InterfaceAccount<'info, TokenAccount>,
+    recipient_token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<u64> {
+    require!(amount > 0, CustomError::InvalidWithdrawalAmount);
+    require!(
+        treasury_token_account.amount >= amount,
+        CustomError::InsufficientTreasuryBalance
+    );
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                from: treasury_token_account.to_account_info(),
+                mint: mint.to_account_info(),
+                to: recipient_token_account.to_account_info(),
+                authority: treasury.clone(),
+            },
+            &[treasury_seeds],
+        ),
+        amount,
+        mint.decimals,
+    )?;
+
+    treasury_token_account.reload()?;
+    Ok(treasury_token_account.amount)
+}
@@ -1,6 +1,14 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { PublicKey, Keypair, Connection, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
+import {
+    PublicKey,
+    Keypair,
+    Connection,
+    LAMPORTS_PER_SOL,
+    SystemProgram,
+    Ed25519Program,
+    SYSVAR_INSTRUCTIONS_PUBKEY,
+} from "@solana/web3.js";
 import { BN } from "bn.js";
 import { expect } from "chai";
 import fs from "fs";
@@ -10,6 +18,7 @@ import { createUmi } from "@metaplex-foundation/umi-bundle-defaults";
 import {
     MPL_CORE_PROGRAM_ID,
     mplCore,
+    fetchAsset,
     fetchCollection
 } from "@metaplex-foundation/mpl-core";
 import {
@@ -54,6 +63,29 @@ describe("rug-pull-chronicles-program", () => {
     // Global seed for config
     let seed = new BN(9876);
 
+    // Payment used for every mint - must be at or above config.minimumPayment
+    const mintPayment = new BN(50_000_000); // 0.05 SOL
+    // Stake locked by standard mints; optional unless config.standardMinReportStake is set
+    const noStake = new BN(0);
+
+    // Typed scam details for the mint instructions, with per-test overrides
+    const buildScamReport = (overrides = {}) => ({
+        scamYear: 2023,
+        usdAmountStolenCents: new BN(100_000_000), // $1,000,000.00
+        platformCategory: 0, // DEX
+        attackType: 0, // Rug Pull
+        chainId: new BN(0), // Solana
+        scamContract: Keypair.generate().publicKey, // A fresh incident unless overridden
+        scammerAddresses: [],
+        evidence: [],
+        ...overrides,
+    });
+
+    // Bytes signed off-chain for the program's Ed25519-checked messages: a domain tag,
+    // the program id and the config key, then the message's Borsh encoding
+    const signedMessage = (tag: string, payload: Buffer[]) =>
+        Buffer.concat([Buffer.from(tag), program.programId.toBuffer(), configPDA.toBuffer(), ...payload]);
+
     // PDAs derived from the seed
     let configPDA: PublicKey;
     let configBump: number;
@@ -109,6 +141,37 @@ describe("rug-pull-chronicles-program", () => {
         console.log("Anti-Scam Treasury PDA:", antiScamTreasuryPDA.toString());
     });
 
+    // Derived once the config PDA is known; mints must pass it after `initFeeSchedule`
+    let feeSchedulePDA: PublicKey;
+    // Derived once the config PDA is known
+    let taxonomyPDA: PublicKey;
+
+    // Helper function to derive a scammer profile PDA address
+    function getScammerProfilePDA(chainId: BN, address: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("scammer"), chainId.toArrayLike(Buffer, "le", 8), address.toBuffer()],
+            program.programId
+        );
+    }
+
+    // Helper function to derive the incident PDA address
+    function getIncidentPDA(chainId: BN, scamContract: PublicKey): [PublicKey, number] {
+        return PublicKey.findProgramAddressSync(
+            [Buffer.from("incident"), chainId.toArrayLike(Buffer, "le", 8), scamContract.toBuffer()],
+            program.programId
+        );
+    }
+
+    // Incident and incident reporter accounts for a mint documenting `report`
+    function getIncidentAccounts(report, reporter: PublicKey) {
+        const [incident] = getIncidentPDA(report.chainId, report.scamContract);
+        const [incidentReporter] = PublicKey.findProgramAddressSync(
+            [Buffer.from("incident_reporter"), incident.toBuffer(), reporter.toBuffer()],
+            program.programId
+        );
+        return { incident, incidentReporter };
+    }
+
     // Helper function to derive the mint tracker PDA address
     function getMintTrackerPDA(mintAddress: PublicKey): [PublicKey, number] {
         return PublicKey.findProgramAddressSync(
@@ -197,6 +260,33 @@ describe("rug-pull-chronicles-program", () => {
         }
     });
 
+    it("Initializes the scam taxonomy", async () => {
+        try {
+            [taxonomyPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("taxonomy"), configPDA.toBuffer()],
+                program.programId
+            );
+
+            await program.methods
+                .initTaxonomy()
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    taxonomy: taxonomyPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            // Seeded with the built-in platform categories and attack types
+            const taxonomy = await program.account.taxonomy.fetch(taxonomyPDA);
+            expect(taxonomy.platformCategories[0].label).to.equal("DEX");
+            expect(taxonomy.attackTypes[0].label).to.equal("Rug Pull");
+        } catch (error) {
+            console.error("Error initializing the taxonomy:", error);
+            throw error;
+        }
+    });
+
     it("Creates a standard collection", async () => {
         try {
             // Collection metadata
@@ -213,7 +303,8 @@ describe("rug-pull-chronicles-program", () => {
                     uri,
                     maxSupply,
                     editionName,
-                    editionUri
+                    editionUri,
+                    { standard: {} }
                 )
                 .accounts({
                     collection: collectionKeypair.publicKey,
@@ -248,16 +339,10 @@ describe("rug-pull-chronicles-program", () => {
                 expect(collectionAccount).to.not.be.null;
                 expect(collectionAccount.data.length).to.be.greaterThan(0);
 
-                // Update the config with the collection address
-                const updateTx = await program.methods
-                    .updateConfigCollection(collectionKeypair.publicKey)
-                    .accounts({
-                        admin: provider.wallet.publicKey,
-                        config: configPDA,
-                    })
-                    .rpc();
-
-                console.log("Config update transaction signature:", updateTx);
+                // The new collection is bound to the config's standard slot
+                const config = await program.account.config.fetch(configPDA);
+                expect(config.standardCollection.toString()).to.equal(collectionKeypair.publicKey.toString());
+                expect(config.standardCollectionMaxSupply).to.equal(maxSupply);
 
                 console.log("Standard collection created successfully with Master Edition");
             } catch (e) {
@@ -288,7 +373,8 @@ describe("rug-pull-chronicles-program", () => {
                     uri,
                     maxSupply,
                     editionName,
-                    editionUri
+                    editionUri,
+                    { scammed: {} }
                 )
                 .accounts({
                     collection: scammedCollectionKeypair.publicKey,
@@ -323,16 +409,10 @@ describe("rug-pull-chronicles-program", () => {
                 expect(collectionAccount).to.not.be.null;
                 expect(collectionAccount.data.length).to.be.greaterThan(0);
 
-                // Update the config with the scammed collection address
-                const updateTx = await program.methods
-                    .updateConfigRuggedCollection(scammedCollectionKeypair.publicKey)
-                    .accounts({
-                        admin: provider.wallet.publicKey,
-                        config: configPDA,
-                    })
-                    .rpc();
-
-                console.log("Config update transaction signature:", updateTx);
+                // The new collection is bound to the config's scammed slot
+                const config = await program.account.config.fetch(configPDA);
+                expect(config.scammedCollection.toString()).to.equal(scammedCollectionKeypair.publicKey.toString());
+                expect(config.scammedCollectionMaxSupply).to.equal(maxSupply);
 
                 console.log("Scammed collection created successfully with Master Edition");
             } catch (e) {
@@ -360,7 +440,8 @@ describe("rug-pull-chronicles-program", () => {
             const tx = await program.methods
                 .addCollectionRoyalties(
                     basisPoints,
-                    [creatorInput]
+                    [creatorInput],
+                    { none: {} }
                 )
                 .accounts({
                     admin: provider.wallet.publicKey,
@@ -409,23 +490,25 @@ describe("rug-pull-chronicles-program", () => {
             const nftUri = "https://rugpullchronicles.io/nft.json";
 
             // Scam attributes data
-            const scamYear = "2023";
-            const usdAmountStolen = "1500000";
-            const platformCategory = "DeFi";
-            const typeOfAttack = "Rug Pull";
+            const report = buildScamReport({
+                scamYear: 2023,
+                usdAmountStolenCents: new BN(150_000_000),
+                platformCategory: 0, // DEX
+                attackType: 0, // Rug Pull
+            });
 
             // Call the mint_standard_nft instruction with attributes
             const tx = await program.methods
                 .mintStandardNft(
                     nftName,
                     nftUri,
-                    scamYear,
-                    usdAmountStolen,
-                    platformCategory,
-                    typeOfAttack
+                    report,
+                    mintPayment,
+                    noStake
                 )
                 .accounts({
                     user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
                     ruggedNftMint: standardNftKeypair.publicKey,
                     standardCollection: collectionKeypair.publicKey,
                     updateAuthorityPda: updateAuthorityPDA,
@@ -478,10 +561,10 @@ describe("rug-pull-chronicles-program", () => {
 
                 // Log that we added these attributes
                 console.log("Scam attributes added to the NFT:");
-                console.log(`- scam_year: ${scamYear}`);
-                console.log(`- usd_amount_stolen: ${usdAmountStolen}`);
-                console.log(`- platform_category: ${platformCategory}`);
-                console.log(`- type_of_attack: ${typeOfAttack}`);
+                console.log("- scam_year: 2023");
+                console.log("- usd_amount_stolen: 1500000.00");
+                console.log("- platform_category: DEX");
+                console.log("- type_of_attack: Rug Pull");
                 console.log(`- minted_by: ${provider.wallet.publicKey.toString()}`);
                 console.log("- minted_at: (timestamp)");
 
@@ -520,14 +603,20 @@ describe("rug-pull-chronicles-program", () => {
             const scamDetails = "This project vanished after raising 2.5M USD in 2023";
 
             // Call the mint_scammed_nft instruction
+            const report = buildScamReport();
             const tx = await program.methods
                 .mintScammedNft(
                     nftName,
                     nftUri,
-                    scamDetails
+                    scamDetails,
+                    report,
+                    mintPayment,
+                    null,
+                    null
                 )
                 .accounts({
                     user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
                     ruggedNftMint: scammedNftKeypair.publicKey,
                     scammedCollection: scammedCollectionKeypair.publicKey,
                     updateAuthorityPda: updateAuthorityPDA,
@@ -599,14 +688,12 @@ describe("rug-pull-chronicles-program", () => {
     it("Updates the fee settings", async () => {
         try {
             // New fee settings
-            const newMintFeeBasisPoints = 750; // 7.5%
             const newTreasuryFeePercent = 70;  // 70% 
             const newAntiScamFeePercent = 30;  // 30%
 
             // Use program.methods as any to bypass TypeScript not knowing about the method
             const tx = await program.methods
                 .updateFeeSettings(
-                    newMintFeeBasisPoints,
                     newTreasuryFeePercent,
                     newAntiScamFeePercent
                 )
@@ -633,7 +720,6 @@ describe("rug-pull-chronicles-program", () => {
             console.log("Updated config:", updatedConfig);
 
             console.log("Fee settings update appears successful");
-            console.log(`Expected mint fee: ${newMintFeeBasisPoints / 100}%`);
             console.log(`Expected treasury fee: ${newTreasuryFeePercent}%`);
             console.log(`Expected anti-scam fee: ${newAntiScamFeePercent}%`);
         } catch (error) {
@@ -661,14 +747,12 @@ describe("rug-pull-chronicles-program", () => {
             });
 
             // Attempt to update fee settings as non-admin
-            const newMintFeeBasisPoints = 800; // 8%
             const newTreasuryFeePercent = 60;
             const newAntiScamFeePercent = 40;
 
             // This should fail with an Unauthorized error
             await program.methods
                 .updateFeeSettings(
-                    newMintFeeBasisPoints,
                     newTreasuryFeePercent,
                     newAntiScamFeePercent
                 )
@@ -718,7 +802,8 @@ describe("rug-pull-chronicles-program", () => {
                     uri,
                     null,
                     null,
-                    null
+                    null,
+                    { standard: {} }
                 )
                 .accounts({
                     collection: newCollectionKeypair.publicKey,
@@ -765,6 +850,7 @@ describe("rug-pull-chronicles-program", () => {
                 .accounts({
                     admin: nonAdminKeypair.publicKey,
                     config: configPDA,
+                    collection: fakeCollectionKeypair.publicKey,
                 })
                 .signers([nonAdminKeypair])
                 .rpc();
@@ -802,7 +888,7 @@ describe("rug-pull-chronicles-program", () => {
             };
 
             await program.methods
-                .addCollectionRoyalties(basisPoints, [creatorInput])
+                .addCollectionRoyalties(basisPoints, [creatorInput], { none: {} })
                 .accounts({
                     admin: nonAdminKeypair.publicKey,
                     config: configPDA,
@@ -826,13 +912,11 @@ describe("rug-pull-chronicles-program", () => {
     it("Should reject invalid fee distributions that don't sum to 100%", async () => {
         try {
             // Attempt to update with invalid fee distribution (sums to 90%)
-            const newMintFeeBasisPoints = 800; // 8%
             const newTreasuryFeePercent = 50;  // 50%
             const newAntiScamFeePercent = 40;  // 40% (Total: 90%)
 
             await program.methods
                 .updateFeeSettings(
-                    newMintFeeBasisPoints,
                     newTreasuryFeePercent,
                     newAntiScamFeePercent
                 )
@@ -851,49 +935,87 @@ describe("rug-pull-chronicles-program", () => {
         }
     });
 
-    it("Should reject excessively high mint fees", async () => {
+    it("Should verify that admin can update collection address", async () => {
         try {
-            // Attempt to update with excessive fee (above 50%)
-            const excessiveMintFeeBasisPoints = 6000; // 60% (exceeds 50% limit)
-            const newTreasuryFeePercent = 50;
-            const newAntiScamFeePercent = 50;
-
+            // Create a new collection under the program's update authority to migrate to
+            const newCollectionKeypair = Keypair.generate();
+            const [newCollectionRecordPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("collection_record"), newCollectionKeypair.publicKey.toBuffer()],
+                program.programId
+            );
             await program.methods
-                .updateFeeSettings(
-                    excessiveMintFeeBasisPoints,
-                    newTreasuryFeePercent,
-                    newAntiScamFeePercent
+                .createCollection(
+                    "Migrated Collection",
+                    "https://example.com/migrated-collection.json",
+                    25,
+                    "Migrated Edition",
+                    "https://example.com/migrated-edition.json",
+                    {
+                        registry: {
+                            kind: { standard: {} },
+                            settings: {
+                                maxSupply: null,
+                                feeOverrides: { minimumPayment: null, treasuryFeePercent: null },
+                                schemaId: 0,
+                                active: false,
+                            },
+                        },
+                    }
                 )
                 .accounts({
-                    admin: provider.wallet.publicKey,
+                    collection: newCollectionKeypair.publicKey,
+                    updateAuthority: updateAuthorityPDA,
+                    payer: provider.wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
                     config: configPDA,
+                    collectionRecord: newCollectionRecordPDA,
                 })
+                .signers([newCollectionKeypair])
                 .rpc();
 
-            // If we reach here, the test has failed
-            expect.fail("Transaction should have failed with InvalidFeeAmount error");
-        } catch (error) {
-            // Check that the error is related to fee amount
-            console.log("Error as expected:", error.message);
-            expect(error.message).to.include("InvalidFeeAmount");
-        }
-    });
-
-    it("Should verify that admin can update collection address", async () => {
-        try {
-            // Create a new collection keypair to simulate a collection migration
-            const newCollectionKeypair = Keypair.generate();
-
             // Get the current standard collection from config
             const configBefore = await program.account.config.fetch(configPDA);
             const originalCollectionAddress = configBefore.standardCollection;
 
+            // A key that isn't a collection under the program's update authority is rejected
+            const notACollection = Keypair.generate().publicKey;
+            try {
+                await program.methods
+                    .updateConfigCollection(notACollection)
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        collection: notACollection,
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with InvalidCollection error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidCollection");
+            }
+
+            // One collection can't fill both config slots
+            try {
+                await program.methods
+                    .updateConfigCollection(configBefore.scammedCollection)
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        collection: configBefore.scammedCollection,
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with InvalidCollection error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidCollection");
+            }
+
             // Update the standard collection address as admin
             const tx = await program.methods
                 .updateConfigCollection(newCollectionKeypair.publicKey)
                 .accounts({
                     admin: provider.wallet.publicKey,
                     config: configPDA,
+                    collection: newCollectionKeypair.publicKey,
                 })
                 .rpc();
 
@@ -903,7 +1025,7 @@ describe("rug-pull-chronicles-program", () => {
                 lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
             });
 
-            // Verify that the collection address was updated
+            // Verify that the collection address and its supply limit were updated
             const configAfter = await program.account.config.fetch(configPDA);
             expect(configAfter.standardCollection.toString()).to.equal(
                 newCollectionKeypair.publicKey.toString(),
@@ -913,6 +1035,9 @@ describe("rug-pull-chronicles-program", () => {
                 originalCollectionAddress.toString(),
                 "Collection address did not change"
             );
+            expect(configAfter.standardCollectionMaxSupply).to.equal(25);
+            // The mint count follows the collection
+            expect(configAfter.totalMintedStandard.toNumber()).to.equal(0);
 
             // Restore the original collection address for future tests
             await program.methods
@@ -920,8 +1045,13 @@ describe("rug-pull-chronicles-program", () => {
                 .accounts({
                     admin: provider.wallet.publicKey,
                     config: configPDA,
+                    collection: originalCollectionAddress,
                 })
                 .rpc();
+            const configRestored = await program.account.config.fetch(configPDA);
+            expect(configRestored.standardCollectionMaxSupply).to.equal(100);
+            expect(configRestored.totalMintedStandard.toNumber())
+                .to.equal(configBefore.totalMintedStandard.toNumber());
         } catch (error) {
             console.error("Error updating collection address:", error);
             throw error;
@@ -930,15 +1060,13 @@ describe("rug-pull-chronicles-program", () => {
 
     it("Should properly handle arithmetic in fee calculations", async () => {
         try {
-            // Set up extreme but valid fees to test arithmetic operations
-            const highMintFeeBasisPoints = 5000; // 50% (maximum allowed)
+            // Set up an uneven split to test arithmetic operations
             const treasuryFeePercent = 75;
             const antiScamFeePercent = 25;
 
             // Update fee settings
             await program.methods
                 .updateFeeSettings(
-                    highMintFeeBasisPoints,
                     treasuryFeePercent,
                     antiScamFeePercent
                 )
@@ -966,18 +1094,19 @@ describe("rug-pull-chronicles-program", () => {
             const treasuryBalanceBefore = await provider.connection.getBalance(treasuryPDA);
             const antiScamBalanceBefore = await provider.connection.getBalance(antiScamTreasuryPDA);
 
-            // Mint a new NFT with the high fees
+            // Mint a new NFT with the uneven split
+            const report = buildScamReport();
             await program.methods
                 .mintStandardNft(
                     "High Fee Test NFT",
                     "https://example.com/high-fee-test.json",
-                    "2023",
-                    "1000000",
-                    "DeFi",
-                    "Test"
+                    report,
+                    mintPayment,
+                    noStake
                 )
                 .accounts({
                     user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
                     ruggedNftMint: nftKeypair.publicKey,
                     standardCollection: collectionKeypair.publicKey,
                     updateAuthorityPda: updateAuthorityPDA,
@@ -995,9 +1124,9 @@ describe("rug-pull-chronicles-program", () => {
             const treasuryBalanceAfter = await provider.connection.getBalance(treasuryPDA);
             const antiScamBalanceAfter = await provider.connection.getBalance(antiScamTreasuryPDA);
 
-            // Calculate expected treasury fee based on minimum payment (not 1 SOL)
-            // Formula: minimum_payment * fee_rate(basis points) / 10000 * treasury_percent / 100
-            const totalFee = minimumPayment.toNumber() * highMintFeeBasisPoints / 10000;
+            // The whole payment is split between the treasuries
+            // Formula: payment_amount * treasury_percent / 100
+            const totalFee = mintPayment.toNumber();
             const expectedTreasuryFee = totalFee * treasuryFeePercent / 100;
             const expectedAntiScamFee = totalFee * antiScamFeePercent / 100;
 
@@ -1023,9 +1152,15 @@ describe("rug-pull-chronicles-program", () => {
                 "Anti-scam fee was not calculated correctly"
             );
 
+            // No lamports are lost to rounding: the treasuries receive the whole payment
+            expect(treasuryIncrease + antiScamIncrease).to.equal(
+                mintPayment.toNumber(),
+                "Treasury and anti-scam shares must add up to the payment"
+            );
+
             // Reset fee settings to original values for other tests
             await program.methods
-                .updateFeeSettings(500, 60, 40) // 5% fee, 60/40 split
+                .updateFeeSettings(60, 40) // 60/40 split
                 .accounts({
                     admin: provider.wallet.publicKey,
                     config: configPDA,
@@ -1037,6 +1172,43 @@ describe("rug-pull-chronicles-program", () => {
         }
     });
 
+    it("Should reject mint payments below the minimum payment", async () => {
+        const config = await program.account.config.fetch(configPDA);
+        const nftKeypair = Keypair.generate();
+
+        try {
+            const report = buildScamReport();
+            await program.methods
+                .mintStandardNft(
+                    "Underpaid NFT",
+                    "https://example.com/underpaid.json",
+                    report,
+                    config.minimumPayment.subn(1),
+                    noStake
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    standardCollection: collectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                })
+                .signers([nftKeypair])
+                .rpc();
+
+            expect.fail("Transaction should have failed with PaymentBelowMinimum error");
+        } catch (error) {
+            console.log("Received expected error for an underpaid mint:", error.message);
+            expect(error.message).to.include("PaymentBelowMinimum");
+        }
+    });
+
     it("Should allow admin to pause and unpause the program", async () => {
         try {
             // Get current pause state
@@ -1068,17 +1240,18 @@ describe("rug-pull-chronicles-program", () => {
             if (config.paused) {
                 try {
                     const nftKeypair = Keypair.generate();
+                    const report = buildScamReport();
                     await program.methods
                         .mintStandardNft(
                             "Test NFT While Paused",
                             "https://example.com/test.json",
-                            "2023",
-                            "1000000",
-                            "DeFi",
-                            "Test"
+                            report,
+                            mintPayment,
+                            noStake
                         )
                         .accounts({
                             user: provider.wallet.publicKey,
+                            ...getIncidentAccounts(report, provider.wallet.publicKey),
                             ruggedNftMint: nftKeypair.publicKey,
                             standardCollection: collectionKeypair.publicKey,
                             updateAuthorityPda: updateAuthorityPDA,
@@ -1217,17 +1390,18 @@ describe("rug-pull-chronicles-program", () => {
 
             // Mint the NFT for the first time (should succeed)
             console.log("Attempting first mint (should succeed)...");
+            const report = buildScamReport();
             const firstMintTx = await program.methods
                 .mintStandardNft(
                     "Duplicate Test NFT",
                     "https://example.com/duplicate-test.json",
-                    "2023",
-                    "1000000",
-                    "DeFi",
-                    "Test"
+                    report,
+                    mintPayment,
+                    noStake
                 )
                 .accounts({
                     user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
                     ruggedNftMint: nftKeypair.publicKey,
                     standardCollection: collectionKeypair.publicKey,
                     updateAuthorityPda: updateAuthorityPDA,
@@ -1253,17 +1427,18 @@ describe("rug-pull-chronicles-program", () => {
             // Try to mint the same NFT again (should fail)
             try {
                 console.log("Attempting duplicate mint (should fail)...");
+                const report = buildScamReport();
                 await program.methods
                     .mintStandardNft(
                         "Duplicate Test NFT",
                         "https://example.com/duplicate-test.json",
-                        "2023",
-                        "1000000",
-                        "DeFi",
-                        "Test"
+                        report,
+                        mintPayment,
+                        noStake
                     )
                     .accounts({
                         user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
                         ruggedNftMint: nftKeypair.publicKey,
                         standardCollection: collectionKeypair.publicKey,
                         updateAuthorityPda: updateAuthorityPDA,
@@ -1302,12 +1477,51 @@ describe("rug-pull-chronicles-program", () => {
             const config = await program.account.config.fetch(configPDA);
             const version = config.version;
 
-            // Initial version should be 1 (set in initialize)
-            expect(version).to.equal(1, "Program version should be 1");
+            // New configs start at the current layout version
+            expect(version).to.equal(2, "Program version should be 2");
             console.log(`Current program version: ${version}`);
 
-            // In a real implementation, there might be an upgrade function 
-            // that increments the version, but we're just checking if it's properly tracked
+            // Migrating a config that is already current changes nothing
+            await program.methods
+                .migrateConfig(seed)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc();
+
+            const migrated = await program.account.config.fetch(configPDA);
+            expect(migrated.version).to.equal(2);
+            expect(migrated.feeRemainderSide).to.deep.equal(config.feeRemainderSide);
+            expect(migrated.totalMintedStandard.toString()).to.equal(config.totalMintedStandard.toString());
+
+            // Only the admin can migrate
+            const nonAdminKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                nonAdminKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+
+            try {
+                await program.methods
+                    .migrateConfig(seed)
+                    .accounts({
+                        admin: nonAdminKeypair.publicKey,
+                        config: configPDA,
+                        systemProgram: anchor.web3.SystemProgram.programId,
+                    })
+                    .signers([nonAdminKeypair])
+                    .rpc();
+                expect.fail("Transaction should have failed with Unauthorized error");
+            } catch (error) {
+                expect(error.message).to.include("Unauthorized");
+            }
         } catch (error) {
             console.error("Error checking program version:", error);
             throw error;
@@ -1324,23 +1538,27 @@ describe("rug-pull-chronicles-program", () => {
             );
 
             // Custom attribute data
-            const scamYear = "2024";
-            const usdAmountStolen = "5000000";
-            const platformCategory = "Wallet";
-            const typeOfAttack = "Social Engineering";
+            const report = buildScamReport({
+                scamYear: 2024,
+                usdAmountStolenCents: new BN(500_000_050),
+                platformCategory: 6, // Wallet
+                attackType: 7, // Social Engineering
+                chainId: new BN(1),
+                scammerAddresses: [Keypair.generate().publicKey],
+            });
 
             // Mint the NFT
             const mintTx = await program.methods
                 .mintStandardNft(
                     "Attribute Test NFT",
                     "https://example.com/attribute-test.json",
-                    scamYear,
-                    usdAmountStolen,
-                    platformCategory,
-                    typeOfAttack
+                    report,
+                    mintPayment,
+                    noStake
                 )
                 .accounts({
                     user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
                     ruggedNftMint: nftKeypair.publicKey,
                     standardCollection: collectionKeypair.publicKey,
                     updateAuthorityPda: updateAuthorityPDA,
@@ -1351,6 +1569,11 @@ describe("rug-pull-chronicles-program", () => {
                     mplCoreProgram: MPL_CORE_PROGRAM_ID,
                     config: configPDA,
                 })
+                .remainingAccounts(report.scammerAddresses.map(address => ({
+                    pubkey: getScammerProfilePDA(report.chainId, address)[0],
+                    isSigner: false,
+                    isWritable: true,
+                })))
                 .signers([nftKeypair])
                 .rpc();
 
@@ -1378,11 +1601,28 @@ describe("rug-pull-chronicles-program", () => {
             const config = await program.account.config.fetch(configPDA);
             expect(config.totalMintedStandard.toNumber()).to.be.greaterThan(0);
 
+            // Verify the typed report stored next to the asset
+            const [scamReportPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("scam_report"), nftKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            const scamReport = await program.account.scamReport.fetch(scamReportPDA);
+            expect(scamReport.asset.toString()).to.equal(nftKeypair.publicKey.toString());
+            expect(scamReport.scamYear).to.equal(2024);
+            expect(scamReport.usdAmountStolenCents.toString()).to.equal("500000050");
+            expect(scamReport.platformCategory).to.equal(6);
+            expect(scamReport.attackType).to.equal(7);
+            expect(scamReport.chainId.toNumber()).to.equal(1);
+            expect(scamReport.status).to.deep.equal({ pending: {} });
+            expect(scamReport.scammerAddresses.map(a => a.toString()))
+                .to.deep.equal(report.scammerAddresses.map(a => a.toString()));
+
             console.log(`Successfully minted NFT with attributes:
-            - scam_year: ${scamYear}
-            - usd_amount_stolen: ${usdAmountStolen}
-            - platform_category: ${platformCategory}
-            - type_of_attack: ${typeOfAttack}
+            - scam_year: 2024
+            - usd_amount_stolen: 5000000.50
+            - platform_category: Wallet
+            - type_of_attack: Social Engineering
+            - chain_id: 1
             - minted_by: ${provider.wallet.publicKey.toString()}
             - minted_at: (timestamp)`);
         } catch (error) {
@@ -1431,7 +1671,18 @@ describe("rug-pull-chronicles-program", () => {
                     "https://example.com/low-supply-collection.json",
                     lowLimit,
                     "Low Supply Edition",
-                    "https://example.com/low-supply-edition.json"
+                    "https://example.com/low-supply-edition.json",
+                    {
+                        registry: {
+                            kind: { standard: {} },
+                            settings: {
+                                maxSupply: null,
+                                feeOverrides: { minimumPayment: null, treasuryFeePercent: null },
+                                schemaId: 0,
+                                active: true,
+                            },
+                        },
+                    }
                 )
                 .accounts({
                     collection: lowLimitCollectionKeypair.publicKey,
@@ -1439,15 +1690,26 @@ describe("rug-pull-chronicles-program", () => {
                     payer: provider.wallet.publicKey,
                     systemProgram: SystemProgram.programId,
                     mplCoreProgram: MPL_CORE_PROGRAM_ID,
-                    config: configPDA
+                    config: configPDA,
+                    collectionRecord: PublicKey.findProgramAddressSync(
+                        [Buffer.from("collection_record"), lowLimitCollectionKeypair.publicKey.toBuffer()],
+                        program.programId
+                    )[0],
                 })
                 .signers([lowLimitCollectionKeypair])
                 .rpc();
 
             console.log(`Created test collection with max supply of ${lowLimit}`);
 
-            // Since our config tracks both standard and scammed collection, 
-            // we can just check if the collection was created with the Master Edition plugin
+            // The registry entry takes the collection's supply limit
+            const [lowLimitRecordPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("collection_record"), lowLimitCollectionKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            const lowLimitRecord = await program.account.collectionRecord.fetch(lowLimitRecordPDA);
+            expect(lowLimitRecord.maxSupply).to.equal(lowLimit);
+
+            // Check that the collection was created with the Master Edition plugin
             const collectionAccount = await provider.connection.getAccountInfo(lowLimitCollectionKeypair.publicKey);
             expect(collectionAccount).to.not.be.null;
             expect(collectionAccount.data.length).to.be.greaterThan(0);
@@ -1469,17 +1731,18 @@ describe("rug-pull-chronicles-program", () => {
             const nftKeypair = Keypair.generate();
 
             // Create the NFT with metadata
+            const report = buildScamReport();
             await program.methods
                 .mintStandardNft(
                     "Freeze Test NFT",
                     "https://example.com/freeze-test.json",
-                    "2022",
-                    "1000000",
-                    "DeFi",
-                    "Rug Pull"
+                    report,
+                    mintPayment,
+                    noStake
                 )
                 .accounts({
                     user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
                     ruggedNftMint: nftKeypair.publicKey,
                     updateAuthorityPda: updateAuthorityPDA,
                     standardCollection: collectionKeypair.publicKey,
@@ -1552,4 +1815,2357 @@ describe("rug-pull-chronicles-program", () => {
             throw error;
         }
     });
-});
\ No newline at end of file
+
+    it("Allows admin to withdraw from the treasuries", async () => {
+        try {
+            const recipient = Keypair.generate();
+            const withdrawAmount = new BN(1_000);
+
+            // Fund the recipient so it stays rent-exempt after receiving small amounts
+            const airdropSig = await provider.connection.requestAirdrop(
+                recipient.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+
+            const treasuryBefore = await provider.connection.getBalance(treasuryPDA);
+            console.log("Treasury balance before withdrawal:", treasuryBefore);
+
+            // Withdraw from the general-ops treasury
+            await program.methods
+                .withdrawTreasury(withdrawAmount)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    treasury: treasuryPDA,
+                    recipient: recipient.publicKey,
+                })
+                .rpc();
+
+            // Withdraw from the anti-scam treasury
+            await program.methods
+                .withdrawAntiscam(withdrawAmount)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    recipient: recipient.publicKey,
+                })
+                .rpc();
+
+            const treasuryAfter = await provider.connection.getBalance(treasuryPDA);
+            expect(treasuryBefore - treasuryAfter).to.equal(withdrawAmount.toNumber());
+
+            const config = await program.account.config.fetch(configPDA);
+            expect(config.totalWithdrawnTreasury.toNumber()).to.be.at.least(withdrawAmount.toNumber());
+            expect(config.totalWithdrawnAntiscam.toNumber()).to.be.at.least(withdrawAmount.toNumber());
+
+            // Draining the treasury below its rent-exempt minimum must fail
+            try {
+                await program.methods
+                    .withdrawTreasury(new BN(treasuryAfter))
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        treasury: treasuryPDA,
+                        recipient: recipient.publicKey,
+                    })
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InsufficientTreasuryBalance error");
+            } catch (error) {
+                console.log("Received expected error when draining the treasury:", error.message);
+                expect(error.message).to.include("InsufficientTreasuryBalance");
+            }
+        } catch (error) {
+            console.error("Error testing treasury withdrawals:", error);
+            throw error;
+        }
+    });
+
+    it("Pays out of the anti-scam treasury through an approved disbursement", async () => {
+        try {
+            const secondApprover = Keypair.generate();
+            const recipient = Keypair.generate();
+
+            // Fund the second approver so it can pay transaction fees, and the recipient so it stays rent-exempt
+            for (const account of [secondApprover, recipient]) {
+                const airdropSig = await provider.connection.requestAirdrop(
+                    account.publicKey,
+                    1 * LAMPORTS_PER_SOL
+                );
+                await provider.connection.confirmTransaction({
+                    signature: airdropSig,
+                    blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                    lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+                });
+            }
+
+            const [councilPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("disbursement_council"), configPDA.toBuffer()],
+                program.programId
+            );
+
+            // Hand the anti-scam treasury to a 2-of-2 council with no minimum delay
+            await program.methods
+                .initDisbursementCouncil([provider.wallet.publicKey, secondApprover.publicKey], 2, new BN(0))
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    council: councilPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            // Direct admin withdrawals from the anti-scam treasury are now disabled
+            try {
+                await program.methods
+                    .withdrawAntiscam(new BN(1_000))
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        recipient: recipient.publicKey,
+                    })
+                    .rpc();
+
+                expect.fail("Transaction should have failed with AntiscamTreasuryGoverned error");
+            } catch (error) {
+                expect(error.message).to.include("AntiscamTreasuryGoverned");
+            }
+
+            const council = await program.account.disbursementCouncil.fetch(councilPDA);
+            const [disbursementPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("disbursement"), councilPDA.toBuffer(), council.proposalCount.toArrayLike(Buffer, "le", 8)],
+                program.programId
+            );
+
+            const amount = new BN(100_000);
+            const recipientBefore = await provider.connection.getBalance(recipient.publicKey);
+            const executeAfter = new BN(Math.floor(Date.now() / 1000));
+            await program.methods
+                .proposeDisbursement(recipient.publicKey, null, amount, "Victim support grant", executeAfter)
+                .accounts({
+                    proposer: provider.wallet.publicKey,
+                    council: councilPDA,
+                    disbursement: disbursementPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            // A single approval is not enough for a 2-of-2 council
+            try {
+                await program.methods
+                    .executeDisbursement()
+                    .accounts({
+                        executor: provider.wallet.publicKey,
+                        config: configPDA,
+                        council: councilPDA,
+                        disbursement: disbursementPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        recipient: recipient.publicKey,
+                    })
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InsufficientApprovals error");
+            } catch (error) {
+                expect(error.message).to.include("InsufficientApprovals");
+            }
+
+            await program.methods
+                .approveDisbursement()
+                .accounts({
+                    approver: secondApprover.publicKey,
+                    council: councilPDA,
+                    disbursement: disbursementPDA,
+                })
+                .signers([secondApprover])
+                .rpc();
+
+            await program.methods
+                .executeDisbursement()
+                .accounts({
+                    executor: provider.wallet.publicKey,
+                    config: configPDA,
+                    council: councilPDA,
+                    disbursement: disbursementPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    recipient: recipient.publicKey,
+                })
+                .rpc();
+
+            const disbursement = await program.account.disbursement.fetch(disbursementPDA);
+            expect(disbursement.status).to.have.property("executed");
+            const recipientAfter = await provider.connection.getBalance(recipient.publicKey);
+            expect(recipientAfter - recipientBefore).to.equal(amount.toNumber());
+        } catch (error) {
+            console.error("Error testing anti-scam disbursements:", error);
+            throw error;
+        }
+    });
+
+    it("Splits mint payments between fee schedule beneficiaries", async () => {
+        try {
+            const partnerNgo = Keypair.generate();
+
+            [feeSchedulePDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("fee_schedule"), configPDA.toBuffer()],
+                program.programId
+            );
+
+            // 50% operations, 30% anti-scam treasury, 20% partner NGO; dust goes to the NGO
+            const beneficiaries = [
+                { address: treasuryPDA, weightBps: 5_000 },
+                { address: antiScamTreasuryPDA, weightBps: 3_000 },
+                { address: partnerNgo.publicKey, weightBps: 2_000 },
+            ];
+
+            // Weights that don't add up to 100% are rejected
+            try {
+                await program.methods
+                    .initFeeSchedule([{ address: partnerNgo.publicKey, weightBps: 9_999 }], 0)
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InvalidFeeSchedule error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidFeeSchedule");
+            }
+
+            await program.methods
+                .initFeeSchedule(beneficiaries, 2)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const nftKeypair = Keypair.generate();
+            const report = buildScamReport();
+            await program.methods
+                .mintStandardNft(
+                    "Fee Schedule NFT",
+                    "https://example.com/fee-schedule.json",
+                    report,
+                    mintPayment,
+                    noStake
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    standardCollection: collectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .remainingAccounts(beneficiaries.map(b => ({
+                    pubkey: b.address,
+                    isSigner: false,
+                    isWritable: true,
+                })))
+                .signers([nftKeypair])
+                .rpc();
+
+            const ngoBalance = await provider.connection.getBalance(partnerNgo.publicKey);
+            expect(ngoBalance).to.equal(mintPayment.toNumber() * 2_000 / 10_000);
+
+            // Switch back to the two-treasury split for the remaining tests
+            await program.methods
+                .updateFeeSchedule(beneficiaries, 2, false)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .rpc();
+        } catch (error) {
+            console.error("Error testing the fee schedule:", error);
+            throw error;
+        }
+    });
+
+    it("Verifies a victim and mints a scammed NFT with the verification record", async () => {
+        try {
+            const [victimVerificationPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("verify_user"), provider.wallet.publicKey.toBuffer()],
+                program.programId
+            );
+            const [verifierPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("verifier"), provider.wallet.publicKey.toBuffer()],
+                program.programId
+            );
+
+            // Only the admin can register verifiers
+            const nonAdminKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                nonAdminKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+
+            try {
+                await program.methods
+                    .addVerifier(nonAdminKeypair.publicKey, "Not The Admin", 2)
+                    .accounts({
+                        admin: nonAdminKeypair.publicKey,
+                        config: configPDA,
+                        verifier: PublicKey.findProgramAddressSync(
+                            [Buffer.from("verifier"), nonAdminKeypair.publicKey.toBuffer()],
+                            program.programId
+                        )[0],
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .signers([nonAdminKeypair])
+                    .rpc();
+
+                expect.fail("Transaction should have failed with Unauthorized error");
+            } catch (error) {
+                expect(error.message).to.include("Unauthorized");
+            }
+
+            // Register the admin's wallet as a verifier scoped to the scammed collection
+            await program.methods
+                .addVerifier(provider.wallet.publicKey, "Triage Desk", 2)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    verifier: verifierPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            // Suspended verifiers can't verify anyone
+            await program.methods
+                .suspendVerifier()
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    verifier: verifierPDA,
+                })
+                .rpc();
+
+            try {
+                await program.methods
+                    .verifyVictim(provider.wallet.publicKey)
+                    .accounts({
+                        authority: provider.wallet.publicKey,
+                        verifier: verifierPDA,
+                        victimVerification: victimVerificationPDA,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InvalidVerifier error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidVerifier");
+            }
+
+            await program.methods
+                .reinstateVerifier()
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    verifier: verifierPDA,
+                })
+                .rpc();
+
+            await program.methods
+                .verifyVictim(provider.wallet.publicKey)
+                .accounts({
+                    authority: provider.wallet.publicKey,
+                    verifier: verifierPDA,
+                    victimVerification: victimVerificationPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const verification = await program.account.victimVerification.fetch(victimVerificationPDA);
+            expect(verification.wallet.toString()).to.equal(provider.wallet.publicKey.toString());
+            expect(verification.verifier.toString()).to.equal(verifierPDA.toString());
+
+            const verifier = await program.account.verifier.fetch(verifierPDA);
+            expect(verifier.verificationsIssued.toNumber()).to.equal(1);
+
+            // With the `scam_verification` feature on, this record is what lets the wallet mint
+            const nftKeypair = Keypair.generate();
+            const report = buildScamReport();
+            await program.methods
+                .mintScammedNft(
+                    "Verified Victim NFT",
+                    "https://example.com/verified-victim.json",
+                    "Wallet drained by a fake airdrop claim site",
+                    report,
+                    mintPayment,
+                    null,
+                    null
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    scammedCollection: scammedCollectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                    victimVerification: victimVerificationPDA,
+                    victimVerifier: verifierPDA,
+                })
+                .signers([nftKeypair])
+                .rpc();
+
+            const asset = await provider.connection.getAccountInfo(nftKeypair.publicKey);
+            expect(asset).to.not.be.null;
+
+            // Only the admin or the issuing verifier can revoke a verification
+            const revokeAccounts = {
+                config: configPDA,
+                victimVerification: victimVerificationPDA,
+                verifiedBy: provider.wallet.publicKey,
+            };
+            const outsider = Keypair.generate();
+            try {
+                await program.methods
+                    .revokeVictimVerification()
+                    .accounts({ ...revokeAccounts, authority: outsider.publicKey })
+                    .signers([outsider])
+                    .rpc();
+                expect.fail("Transaction should have failed with Unauthorized error");
+            } catch (error) {
+                expect(error.message).to.include("Unauthorized");
+            }
+
+            await program.methods
+                .revokeVictimVerification()
+                .accounts({ ...revokeAccounts, authority: provider.wallet.publicKey })
+                .rpc();
+            expect(await provider.connection.getAccountInfo(victimVerificationPDA)).to.be.null;
+
+            // A revoked wallet can be verified again
+            await program.methods
+                .verifyVictim(provider.wallet.publicKey)
+                .accounts({
+                    authority: provider.wallet.publicKey,
+                    verifier: verifierPDA,
+                    victimVerification: victimVerificationPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            expect((await program.account.verifier.fetch(verifierPDA)).verificationsIssued.toNumber())
+                .to.equal(2);
+        } catch (error) {
+            console.error("Error testing victim verification:", error);
+            throw error;
+        }
+    });
+
+    it("Mints a scammed NFT with a signed victim attestation and blocks replays", async () => {
+        try {
+            // An off-chain triage service that signs attestations instead of sending transactions
+            const triageKeypair = Keypair.generate();
+            const [triageVerifierPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("verifier"), triageKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            await program.methods
+                .addVerifier(triageKeypair.publicKey, "Triage Service", 2)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    verifier: triageVerifierPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const victimKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                victimKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+
+            const attestation = {
+                victim: victimKeypair.publicKey,
+                compromisedAddress: Keypair.generate().publicKey,
+                expiresAt: new BN(Math.floor(Date.now() / 1000) + 600),
+                nonce: new BN(1),
+            };
+            // The signed message is the tagged, program- and config-bound Borsh encoding of the attestation
+            const message = signedMessage("rpc:victim_attestation", [
+                attestation.victim.toBuffer(),
+                attestation.compromisedAddress.toBuffer(),
+                attestation.expiresAt.toArrayLike(Buffer, "le", 8),
+                attestation.nonce.toArrayLike(Buffer, "le", 8),
+            ]);
+            const ed25519Ix = Ed25519Program.createInstructionWithPrivateKey({
+                privateKey: triageKeypair.secretKey,
+                message,
+            });
+            const [attestationNoncePDA] = PublicKey.findProgramAddressSync(
+                [
+                    Buffer.from("attestation_nonce"),
+                    triageKeypair.publicKey.toBuffer(),
+                    attestation.nonce.toArrayLike(Buffer, "le", 8),
+                ],
+                program.programId
+            );
+
+            const mintWithAttestation = async (nftKeypair: Keypair, signatureIx = ed25519Ix) => {
+                const report = buildScamReport();
+                return program.methods
+                    .mintScammedNft(
+                        "Attested Victim NFT",
+                        "https://example.com/attested-victim.json",
+                        "Approved a malicious token allowance",
+                        report,
+                        mintPayment,
+                        attestation,
+                        null
+                    )
+                    .accounts({
+                        user: victimKeypair.publicKey,
+                        ...getIncidentAccounts(report, victimKeypair.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        scammedCollection: scammedCollectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                        attestationVerifier: triageVerifierPDA,
+                        attestationNonce: attestationNoncePDA,
+                        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                    })
+                    .preInstructions([signatureIx])
+                    .signers([victimKeypair, nftKeypair])
+                    .rpc();
+            };
+
+            // A signature over the bare attestation, as any other deployment would accept it, is refused
+            try {
+                await mintWithAttestation(
+                    Keypair.generate(),
+                    Ed25519Program.createInstructionWithPrivateKey({
+                        privateKey: triageKeypair.secretKey,
+                        message: message.subarray(Buffer.from("rpc:victim_attestation").length + 64),
+                    })
+                );
+                expect.fail("Transaction should have failed with InvalidAttestation error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidAttestation");
+            }
+
+            const nftKeypair = Keypair.generate();
+            await mintWithAttestation(nftKeypair);
+
+            const asset = await provider.connection.getAccountInfo(nftKeypair.publicKey);
+            expect(asset).to.not.be.null;
+            const triageVerifier = await program.account.verifier.fetch(triageVerifierPDA);
+            expect(triageVerifier.verificationsIssued.toNumber()).to.equal(1);
+
+            // The same attestation can't be used for a second mint
+            try {
+                await mintWithAttestation(Keypair.generate());
+                expect.fail("Transaction should have failed with AttestationReplayed error");
+            } catch (error) {
+                expect(error.message).to.include("AttestationReplayed");
+            }
+        } catch (error) {
+            console.error("Error testing signed victim attestations:", error);
+            throw error;
+        }
+    });
+
+    it("Mints a scammed NFT to a safe wallet authorized by the compromised wallet", async () => {
+        try {
+            // The victim signs from a fresh safe wallet; the drained wallet only pre-signs
+            const safeWallet = Keypair.generate();
+            const compromisedWallet = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                safeWallet.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+
+            // A verifier ties the drained wallet to the safe wallet through an attestation
+            const triageKeypair = Keypair.generate();
+            const [triageVerifierPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("verifier"), triageKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            await program.methods
+                .addVerifier(triageKeypair.publicKey, "Safe Wallet Triage", 2)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    verifier: triageVerifierPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const expiresAt = new BN(Math.floor(Date.now() / 1000) + 300);
+            const authorize = (destination: PublicKey, nonce: BN, destinationTag: string) => {
+                const safeDestination = {
+                    compromised: compromisedWallet.publicKey,
+                    destination,
+                    expiresAt,
+                    nonce,
+                };
+                const attestation = {
+                    victim: safeWallet.publicKey,
+                    compromisedAddress: compromisedWallet.publicKey,
+                    expiresAt,
+                    nonce,
+                };
+                // The signed messages are the tagged, program- and config-bound Borsh encodings
+                // of the authorization and attestation
+                const destinationIx = Ed25519Program.createInstructionWithPrivateKey({
+                    privateKey: compromisedWallet.secretKey,
+                    message: signedMessage(destinationTag, [
+                        safeDestination.compromised.toBuffer(),
+                        safeDestination.destination.toBuffer(),
+                        safeDestination.expiresAt.toArrayLike(Buffer, "le", 8),
+                        safeDestination.nonce.toArrayLike(Buffer, "le", 8),
+                    ]),
+                });
+                const attestationIx = Ed25519Program.createInstructionWithPrivateKey({
+                    privateKey: triageKeypair.secretKey,
+                    message: signedMessage("rpc:victim_attestation", [
+                        attestation.victim.toBuffer(),
+                        attestation.compromisedAddress.toBuffer(),
+                        attestation.expiresAt.toArrayLike(Buffer, "le", 8),
+                        attestation.nonce.toArrayLike(Buffer, "le", 8),
+                    ]),
+                });
+                return { safeDestination, attestation, preInstructions: [destinationIx, attestationIx] };
+            };
+
+            const mintToDestination = async (
+                nftKeypair: Keypair,
+                destination: PublicKey,
+                nonce: BN,
+                destinationTag = "rpc:safe_destination"
+            ) => {
+                const { safeDestination, attestation, preInstructions } = authorize(destination, nonce, destinationTag);
+                const report = buildScamReport();
+                const nonceBytes = nonce.toArrayLike(Buffer, "le", 8);
+                return program.methods
+                    .mintScammedNft(
+                        "Safe Destination NFT",
+                        "https://example.com/safe-destination.json",
+                        "Seed phrase phished through a fake wallet update",
+                        report,
+                        mintPayment,
+                        attestation,
+                        safeDestination
+                    )
+                    .accounts({
+                        user: safeWallet.publicKey,
+                        ...getIncidentAccounts(report, safeWallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        scammedCollection: scammedCollectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                        attestationVerifier: triageVerifierPDA,
+                        attestationNonce: PublicKey.findProgramAddressSync(
+                            [Buffer.from("attestation_nonce"), triageKeypair.publicKey.toBuffer(), nonceBytes],
+                            program.programId
+                        )[0],
+                        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                        destination,
+                        destinationNonce: PublicKey.findProgramAddressSync(
+                            [Buffer.from("destination_nonce"), compromisedWallet.publicKey.toBuffer(), nonceBytes],
+                            program.programId
+                        )[0],
+                    })
+                    .preInstructions(preInstructions)
+                    .signers([safeWallet, nftKeypair])
+                    .rpc();
+            };
+
+            // Minting back into the drained wallet is refused
+            try {
+                await mintToDestination(Keypair.generate(), compromisedWallet.publicKey, new BN(1));
+                expect.fail("Transaction should have failed with InvalidDestination error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidDestination");
+            }
+
+            // The compromised wallet's signature only counts under the safe-destination tag
+            const coldWallet = Keypair.generate().publicKey;
+            try {
+                await mintToDestination(Keypair.generate(), coldWallet, new BN(3), "rpc:victim_attestation");
+                expect.fail("Transaction should have failed with DestinationNotAuthorized error");
+            } catch (error) {
+                expect(error.message).to.include("DestinationNotAuthorized");
+            }
+
+            // The asset can go to a cold wallet that isn't the signer either
+            const nftKeypair = Keypair.generate();
+            await mintToDestination(nftKeypair, coldWallet, new BN(2));
+
+            // MPL Core assets store the owner right after the account key byte
+            const asset = await provider.connection.getAccountInfo(nftKeypair.publicKey);
+            const owner = new PublicKey(asset.data.subarray(1, 33));
+            expect(owner.toString()).to.equal(coldWallet.toString());
+
+            // A pre-signed authorization can't be replayed
+            try {
+                await mintToDestination(Keypair.generate(), coldWallet, new BN(2));
+                expect.fail("Transaction should have failed with DestinationAuthorizationReplayed error");
+            } catch (error) {
+                expect(error.message).to.include("DestinationAuthorizationReplayed");
+            }
+        } catch (error) {
+            console.error("Error testing safe-destination mints:", error);
+            throw error;
+        }
+    });
+
+    it("Should reject malformed scam reports", async () => {
+        const scammer = Keypair.generate().publicKey;
+        const invalidReports = [
+            // Before any blockchain existed
+            buildScamReport({ scamYear: 2008 }),
+            // In the future
+            buildScamReport({ scamYear: new Date().getUTCFullYear() + 1 }),
+            // The same scammer address twice
+            buildScamReport({ scammerAddresses: [scammer, scammer] }),
+            // More scammer addresses than a report can hold
+            buildScamReport({
+                scammerAddresses: Array.from({ length: 6 }, () => Keypair.generate().publicKey),
+            }),
+            // No scam contract to identify the incident by
+            buildScamReport({ scamContract: PublicKey.default }),
+        ];
+
+        for (const report of invalidReports) {
+            const nftKeypair = Keypair.generate();
+            try {
+                await program.methods
+                    .mintStandardNft(
+                        "Malformed Report NFT",
+                        "https://example.com/malformed-report.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    .signers([nftKeypair])
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InvalidTraits error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidTraits");
+            }
+        }
+    });
+
+    it("Extends the taxonomy and rejects deprecated or unknown entries", async () => {
+        try {
+            const mintWithReport = async (report) => {
+                const nftKeypair = Keypair.generate();
+                await program.methods
+                    .mintStandardNft(
+                        "Taxonomy NFT",
+                        "https://example.com/taxonomy.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                        taxonomy: taxonomyPDA,
+                    })
+                    .signers([nftKeypair])
+                    .rpc();
+            };
+
+            // Labels are unique regardless of case
+            try {
+                await program.methods
+                    .addTaxonomyEntry({ platformCategory: {} }, "dex")
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        taxonomy: taxonomyPDA,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InvalidTaxonomyEntry error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidTaxonomyEntry");
+            }
+
+            await program.methods
+                .addTaxonomyEntry({ attackType: {} }, "Address Poisoning")
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    taxonomy: taxonomyPDA,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+
+            const taxonomy = await program.account.taxonomy.fetch(taxonomyPDA);
+            const addressPoisoning = taxonomy.attackTypes[taxonomy.attackTypes.length - 1];
+            expect(addressPoisoning.label).to.equal("Address Poisoning");
+
+            // The new id can be used right away
+            await mintWithReport(buildScamReport({ attackType: addressPoisoning.id }));
+
+            // Unknown ids are rejected
+            try {
+                await mintWithReport(buildScamReport({ attackType: addressPoisoning.id + 1 }));
+                expect.fail("Transaction should have failed with InvalidTraits error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidTraits");
+            }
+
+            // Deprecated entries can't be used for new reports
+            await program.methods
+                .setTaxonomyEntryDeprecated({ attackType: {} }, addressPoisoning.id, true)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    taxonomy: taxonomyPDA,
+                })
+                .rpc();
+
+            try {
+                await mintWithReport(buildScamReport({ attackType: addressPoisoning.id }));
+                expect.fail("Transaction should have failed with InvalidTraits error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidTraits");
+            }
+        } catch (error) {
+            console.error("Error testing the taxonomy:", error);
+            throw error;
+        }
+    });
+
+    it("Aggregates reports about the same scammer address", async () => {
+        try {
+            const scammer = Keypair.generate().publicKey;
+            const chainId = new BN(56); // BNB Smart Chain
+            const [profilePDA] = getScammerProfilePDA(chainId, scammer);
+
+            const mintReport = async (usdAmountStolenCents: BN) => {
+                const nftKeypair = Keypair.generate();
+                const report = buildScamReport({ chainId, usdAmountStolenCents, scammerAddresses: [scammer] });
+                await program.methods
+                    .mintStandardNft(
+                        "Repeat Scammer NFT",
+                        "https://example.com/repeat-scammer.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    // The fee schedule is inactive, so scammer profiles come first
+                    .remainingAccounts([{ pubkey: profilePDA, isSigner: false, isWritable: true }])
+                    .signers([nftKeypair])
+                    .rpc();
+                return nftKeypair.publicKey;
+            };
+
+            const firstAsset = await mintReport(new BN(12_345));
+            const secondAsset = await mintReport(new BN(10_000));
+
+            const profile = await program.account.scammerProfile.fetch(profilePDA);
+            expect(profile.chainId.toNumber()).to.equal(56);
+            expect(profile.address.toString()).to.equal(scammer.toString());
+            expect(profile.reportCount.toNumber()).to.equal(2);
+            expect(profile.verifiedReportCount.toNumber()).to.equal(0);
+            expect(profile.totalUsdStolenCents.toNumber()).to.equal(22_345);
+            expect(profile.lastSeen.toNumber()).to.be.at.least(profile.firstSeen.toNumber());
+            expect(profile.linkedAssets.map(a => a.toString()))
+                .to.deep.equal([firstAsset.toString(), secondAsset.toString()]);
+
+            // A report naming a scammer without passing its profile is rejected
+            try {
+                const nftKeypair = Keypair.generate();
+                const report = buildScamReport({ chainId, scammerAddresses: [scammer] });
+                await program.methods
+                    .mintStandardNft(
+                        "Missing Profile NFT",
+                        "https://example.com/missing-profile.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    .signers([nftKeypair])
+                    .rpc();
+
+                expect.fail("Transaction should have failed with InvalidScammerProfile error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidScammerProfile");
+            }
+        } catch (error) {
+            console.error("Error testing scammer profiles:", error);
+            throw error;
+        }
+    });
+
+    it("Reports an address's risk summary through check_address", async () => {
+        try {
+            const moderatorKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                moderatorKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+            await program.methods
+                .addModerator(moderatorKeypair.publicKey)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+
+            const scammer = Keypair.generate().publicKey;
+            const chainId = new BN(1);
+            const [profilePDA] = getScammerProfilePDA(chainId, scammer);
+            const profileAccounts = [{ pubkey: profilePDA, isSigner: false, isWritable: true }];
+            const nftKeypair = Keypair.generate();
+            const report = buildScamReport({ chainId, scammerAddresses: [scammer] });
+            await program.methods
+                .mintStandardNft(
+                    "Risk Summary NFT",
+                    "https://example.com/risk-summary.json",
+                    report,
+                    mintPayment,
+                    noStake
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    standardCollection: collectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .remainingAccounts(profileAccounts)
+                .signers([nftKeypair])
+                .rpc();
+            const checkAddress = () =>
+                program.methods
+                    .checkAddress(chainId, scammer)
+                    .accounts({
+                        scammerProfile: profilePDA,
+                    })
+                    .view();
+
+            // An unmoderated report by an unverified reporter doesn't flag the address
+            const pending = await checkAddress();
+            expect(pending.flagged).to.be.false;
+            expect(pending.reportCount.toNumber()).to.equal(1);
+            expect(pending.approvedReportCount.toNumber()).to.equal(0);
+
+            await program.methods
+                .approveReport()
+                .accounts({
+                    authority: moderatorKeypair.publicKey,
+                    config: configPDA,
+                    asset: nftKeypair.publicKey,
+                    collection: collectionKeypair.publicKey,
+                    collectionRecord: null,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                })
+                .remainingAccounts(profileAccounts)
+                .signers([moderatorKeypair])
+                .rpc();
+
+            const profile = await program.account.scammerProfile.fetch(profilePDA);
+            const summary = await checkAddress();
+            expect(summary.flagged).to.be.true;
+            expect(summary.reportCount.toNumber()).to.equal(profile.reportCount.toNumber());
+            expect(summary.verifiedReportCount.toNumber())
+                .to.equal(profile.verifiedReportCount.toNumber());
+            expect(summary.approvedReportCount.toNumber()).to.equal(1);
+            expect(summary.lastReportAt.toNumber()).to.equal(profile.lastSeen.toNumber());
+
+            // An address nobody has reported comes back clean
+            const unknown = Keypair.generate().publicKey;
+            const clean = await program.methods
+                .checkAddress(new BN(1), unknown)
+                .accounts({
+                    scammerProfile: getScammerProfilePDA(new BN(1), unknown)[0],
+                })
+                .view();
+            expect(clean.flagged).to.be.false;
+            expect(clean.reportCount.toNumber()).to.equal(0);
+            expect(clean.verifiedReportCount.toNumber()).to.equal(0);
+            expect(clean.lastReportAt.toNumber()).to.equal(0);
+        } catch (error) {
+            console.error("Error testing check_address:", error);
+            throw error;
+        }
+    });
+
+    it("Links reports of the same scam to one incident and blocks repeat reporters", async () => {
+        try {
+            const scamContract = Keypair.generate().publicKey;
+            const chainId = new BN(1); // Ethereum
+            const [incidentPDA] = getIncidentPDA(chainId, scamContract);
+
+            const otherVictim = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                otherVictim.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+
+            const mintForIncident = async (user: Keypair | null) => {
+                const reporter = user ? user.publicKey : provider.wallet.publicKey;
+                const nftKeypair = Keypair.generate();
+                const report = buildScamReport({ chainId, scamContract });
+                await program.methods
+                    .mintStandardNft(
+                        "Shared Incident NFT",
+                        "https://example.com/shared-incident.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: reporter,
+                        ...getIncidentAccounts(report, reporter),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    .signers(user ? [user, nftKeypair] : [nftKeypair])
+                    .rpc();
+                return nftKeypair.publicKey;
+            };
+
+            const firstAsset = await mintForIncident(null);
+            await mintForIncident(otherVictim);
+
+            const incident = await program.account.incident.fetch(incidentPDA);
+            expect(incident.chainId.toNumber()).to.equal(1);
+            expect(incident.scamContract.toString()).to.equal(scamContract.toString());
+            expect(incident.reporterCount).to.equal(2);
+            expect(incident.firstAsset.toString()).to.equal(firstAsset.toString());
+
+            const scamReport = await program.account.scamReport.fetch(
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("scam_report"), firstAsset.toBuffer()],
+                    program.programId
+                )[0]
+            );
+            expect(scamReport.incident.toString()).to.equal(incidentPDA.toString());
+
+            // The same wallet can't document the same incident twice
+            try {
+                await mintForIncident(otherVictim);
+                expect.fail("Transaction should have failed with DuplicateNFTMint error");
+            } catch (error) {
+                expect(error.message).to.include("DuplicateNFTMint");
+            }
+        } catch (error) {
+            console.error("Error testing incidents:", error);
+            throw error;
+        }
+    });
+
+    it("Attaches evidence at mint time and appends more with add_evidence", async () => {
+        try {
+            const nftKeypair = Keypair.generate();
+            const [evidencePDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("evidence"), nftKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            const report = buildScamReport({
+                evidence: [
+                    { kind: { transactionSignature: {} }, data: Buffer.alloc(64, 7) },
+                    { kind: { sha256: {} }, data: Buffer.alloc(32, 1) },
+                ],
+            });
+            await program.methods
+                .mintStandardNft(
+                    "Evidence NFT",
+                    "https://example.com/evidence.json",
+                    report,
+                    mintPayment,
+                    noStake
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    standardCollection: collectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .signers([nftKeypair])
+                .rpc();
+
+            let evidence = await program.account.evidence.fetch(evidencePDA);
+            expect(evidence.asset.toString()).to.equal(nftKeypair.publicKey.toString());
+            expect(evidence.entries.length).to.equal(2);
+            expect(evidence.entries[0].submitter.toString()).to.equal(provider.wallet.publicKey.toString());
+
+            const [scamReportPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("scam_report"), nftKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+
+            // The reporter appends an IPFS CID; the account grows to fit it
+            const cid = Buffer.alloc(36, 9);
+            await program.methods
+                .addEvidence([{ kind: { ipfsCid: {} }, data: cid }])
+                .accounts({
+                    submitter: provider.wallet.publicKey,
+                    config: configPDA,
+                    scamReport: scamReportPDA,
+                    evidence: evidencePDA,
+                })
+                .rpc();
+
+            evidence = await program.account.evidence.fetch(evidencePDA);
+            expect(evidence.entries.length).to.equal(3);
+            expect(evidence.entries[2].kind).to.deep.equal({ ipfsCid: {} });
+            expect(Buffer.from(evidence.entries[2].data).equals(cid)).to.be.true;
+
+            // A SHA-256 digest must be exactly 32 bytes
+            try {
+                await program.methods
+                    .addEvidence([{ kind: { sha256: {} }, data: Buffer.alloc(31) }])
+                    .accounts({
+                        submitter: provider.wallet.publicKey,
+                        config: configPDA,
+                        scamReport: scamReportPDA,
+                        evidence: evidencePDA,
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with InvalidEvidence error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidEvidence");
+            }
+
+            // Only the reporter or the admin can add evidence
+            const stranger = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                stranger.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+            try {
+                await program.methods
+                    .addEvidence([{ kind: { arweaveTx: {} }, data: Buffer.alloc(32, 2) }])
+                    .accounts({
+                        submitter: stranger.publicKey,
+                        config: configPDA,
+                        scamReport: scamReportPDA,
+                        evidence: evidencePDA,
+                    })
+                    .signers([stranger])
+                    .rpc();
+                expect.fail("Transaction should have failed with Unauthorized error");
+            } catch (error) {
+                expect(error.message).to.include("Unauthorized");
+            }
+        } catch (error) {
+            console.error("Error testing evidence:", error);
+            throw error;
+        }
+    });
+
+    it("Holds new reports for moderation and approves or rejects them", async () => {
+        try {
+            const moderatorKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                moderatorKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+            const [moderatorPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("moderator"), moderatorKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            await program.methods
+                .addModerator(moderatorKeypair.publicKey)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+
+            const profileAccounts = (chainId: BN, scammerAddresses: PublicKey[]) =>
+                scammerAddresses.map(address => ({
+                    pubkey: getScammerProfilePDA(chainId, address)[0],
+                    isSigner: false,
+                    isWritable: true,
+                }));
+            const mintPending = async (scammerAddresses: PublicKey[] = []) => {
+                const nftKeypair = Keypair.generate();
+                const report = buildScamReport({ scammerAddresses });
+                await program.methods
+                    .mintStandardNft(
+                        "Pending Report NFT",
+                        "https://example.com/pending-report.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    .remainingAccounts(profileAccounts(report.chainId, scammerAddresses))
+                    .signers([nftKeypair])
+                    .rpc();
+                return nftKeypair.publicKey;
+            };
+            const moderationAccounts = (asset: PublicKey) => ({
+                authority: moderatorKeypair.publicKey,
+                config: configPDA,
+                asset,
+                collection: collectionKeypair.publicKey,
+                collectionRecord: null,
+                updateAuthorityPda: updateAuthorityPDA,
+                mplCoreProgram: MPL_CORE_PROGRAM_ID,
+            });
+            const statusOf = async (asset: PublicKey) => {
+                const onChain = await fetchAsset(umi, asset.toString());
+                const attribute = onChain.attributes.attributeList.find(a => a.key === "report_status");
+                return { status: attribute.value, frozen: onChain.permanentFreezeDelegate.frozen };
+            };
+
+            // New reports start pending, with the asset frozen
+            const approvedAsset = await mintPending();
+            expect(await statusOf(approvedAsset)).to.deep.equal({ status: "Pending", frozen: true });
+
+            await program.methods
+                .approveReport()
+                .accounts(moderationAccounts(approvedAsset))
+                .signers([moderatorKeypair])
+                .rpc();
+            expect(await statusOf(approvedAsset)).to.deep.equal({ status: "Approved", frozen: false });
+            const [approvedReportPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("scam_report"), approvedAsset.toBuffer()],
+                program.programId
+            );
+            const approvedReport = await program.account.scamReport.fetch(approvedReportPDA);
+            expect(approvedReport.status).to.deep.equal({ approved: {} });
+            expect(approvedReport.moderator.toString()).to.equal(moderatorPDA.toString());
+
+            // A decided report can't be moderated again
+            try {
+                await program.methods
+                    .rejectReport({ spam: {} }, false)
+                    .accounts(moderationAccounts(approvedAsset))
+                    .signers([moderatorKeypair])
+                    .rpc();
+                expect.fail("Transaction should have failed with ReportAlreadyModerated error");
+            } catch (error) {
+                expect(error.message).to.include("ReportAlreadyModerated");
+            }
+
+            // Rejected without burning: the asset stays frozen and records the reason
+            const frozenAsset = await mintPending();
+            await program.methods
+                .rejectReport({ defamation: {} }, false)
+                .accounts(moderationAccounts(frozenAsset))
+                .signers([moderatorKeypair])
+                .rpc();
+            expect(await statusOf(frozenAsset)).to.deep.equal({ status: "Rejected", frozen: true });
+            const frozenOnChain = await fetchAsset(umi, frozenAsset.toString());
+            expect(frozenOnChain.attributes.attributeList.find(a => a.key === "rejection_reason").value)
+                .to.equal("Defamation");
+
+            // Rejected and burned
+            const burnedAsset = await mintPending();
+            await program.methods
+                .rejectReport({ spam: {} }, true)
+                .accounts(moderationAccounts(burnedAsset))
+                .signers([moderatorKeypair])
+                .rpc();
+            const burned = await provider.connection.getAccountInfo(burnedAsset);
+            expect(burned === null || burned.data.length <= 1).to.be.true;
+
+            const moderator = await program.account.moderator.fetch(moderatorPDA);
+            expect(moderator.reportsModerated.toNumber()).to.equal(3);
+
+            // A rejected report no longer counts toward its scammers' profiles
+            const scammer = Keypair.generate().publicKey;
+            const [profilePDA] = getScammerProfilePDA(new BN(0), scammer);
+            const slanderedAsset = await mintPending([scammer]);
+            let profile = await program.account.scammerProfile.fetch(profilePDA);
+            expect(profile.reportCount.toNumber()).to.equal(1);
+            await program.methods
+                .rejectReport({ defamation: {} }, false)
+                .accounts(moderationAccounts(slanderedAsset))
+                .remainingAccounts(profileAccounts(new BN(0), [scammer]))
+                .signers([moderatorKeypair])
+                .rpc();
+            profile = await program.account.scammerProfile.fetch(profilePDA);
+            expect(profile.reportCount.toNumber()).to.equal(0);
+            expect(profile.totalUsdStolenCents.toNumber()).to.equal(0);
+            expect(profile.linkedAssets).to.be.empty;
+
+            // Only registered moderators can decide reports
+            const pendingAsset = await mintPending();
+            const outsider = Keypair.generate();
+            try {
+                await program.methods
+                    .approveReport()
+                    .accounts({ ...moderationAccounts(pendingAsset), authority: outsider.publicKey })
+                    .signers([outsider])
+                    .rpc();
+                expect.fail("Approval by a non-moderator should have failed");
+            } catch (error) {
+                expect(error.message).to.include("AccountNotInitialized");
+            }
+        } catch (error) {
+            console.error("Error testing moderation:", error);
+            throw error;
+        }
+    });
+
+    it("Resolves bonded challenges against reports", async () => {
+        try {
+            const fundedKeypair = async () => {
+                const keypair = Keypair.generate();
+                const airdropSig = await provider.connection.requestAirdrop(
+                    keypair.publicKey,
+                    2 * LAMPORTS_PER_SOL
+                );
+                await provider.connection.confirmTransaction({
+                    signature: airdropSig,
+                    blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                    lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+                });
+                return keypair;
+            };
+
+            const scammer = Keypair.generate().publicKey;
+            const [profilePDA] = getScammerProfilePDA(new BN(0), scammer);
+            const profileAccounts = [{ pubkey: profilePDA, isSigner: false, isWritable: true }];
+            const nftKeypair = Keypair.generate();
+            const report = buildScamReport({ scammerAddresses: [scammer] });
+            await program.methods
+                .mintStandardNft(
+                    "Challenged Report NFT",
+                    "https://example.com/challenged-report.json",
+                    report,
+                    mintPayment,
+                    noStake
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    standardCollection: collectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .remainingAccounts(profileAccounts)
+                .signers([nftKeypair])
+                .rpc();
+            const asset = nftKeypair.publicKey;
+            const bond = new BN(100_000_000); // 0.1 SOL
+
+            const openChallenge = (challenger: Keypair, bondLamports: BN) =>
+                program.methods
+                    .openChallenge(
+                        "The team still runs the project; liquidity was migrated, not pulled",
+                        "https://example.com/migration-proof.json",
+                        bondLamports
+                    )
+                    .accounts({ challenger: challenger.publicKey, asset })
+                    .signers([challenger])
+                    .rpc();
+            const challengePDA = (challenger: Keypair) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("challenge"), asset.toBuffer(), challenger.publicKey.toBuffer()],
+                    program.programId
+                )[0];
+            const resolveChallenge = (challenger: Keypair, upheld: boolean) =>
+                program.methods
+                    .resolveChallenge(upheld)
+                    .accounts({
+                        authority: provider.wallet.publicKey,
+                        moderator: null,
+                        config: configPDA,
+                        asset,
+                        challenge: challengePDA(challenger),
+                        challenger: challenger.publicKey,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        collection: collectionKeypair.publicKey,
+                        collectionRecord: null,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    })
+                    .remainingAccounts(upheld ? profileAccounts : [])
+                    .rpc();
+
+            // The bond must meet the minimum
+            const cheapChallenger = await fundedKeypair();
+            try {
+                await openChallenge(cheapChallenger, bond.subn(1));
+                expect.fail("Transaction should have failed with InvalidChallenge error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidChallenge");
+            }
+
+            // A rejected challenge's bond goes to the anti-scam treasury
+            const losingChallenger = await fundedKeypair();
+            await openChallenge(losingChallenger, bond);
+            const challenge = await program.account.challenge.fetch(challengePDA(losingChallenger));
+            expect(challenge.status).to.deep.equal({ open: {} });
+            expect(challenge.bondLamports.toString()).to.equal(bond.toString());
+            expect(challenge.resolutionDeadline.toNumber() - challenge.openedAt.toNumber())
+                .to.equal(14 * 24 * 60 * 60);
+
+            // The challenger can't reclaim the bond before the deadline
+            try {
+                await program.methods
+                    .expireChallenge()
+                    .accounts({
+                        challenger: losingChallenger.publicKey,
+                        challenge: challengePDA(losingChallenger),
+                    })
+                    .signers([losingChallenger])
+                    .rpc();
+                expect.fail("Transaction should have failed with ChallengeDeadlineNotReached error");
+            } catch (error) {
+                expect(error.message).to.include("ChallengeDeadlineNotReached");
+            }
+
+            const [scamReportPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("scam_report"), asset.toBuffer()],
+                program.programId
+            );
+            const challengedReport = await program.account.scamReport.fetch(scamReportPDA);
+            expect(challengedReport.openChallenges).to.equal(1);
+            expect(challengedReport.challengeDeadline.toString()).to.equal(challenge.resolutionDeadline.toString());
+
+            const antiScamBefore = await provider.connection.getBalance(antiScamTreasuryPDA);
+            await resolveChallenge(losingChallenger, false);
+            const antiScamAfter = await provider.connection.getBalance(antiScamTreasuryPDA);
+            expect(antiScamAfter - antiScamBefore).to.equal(bond.toNumber());
+            // Resolved challenges are closed
+            expect(await provider.connection.getAccountInfo(challengePDA(losingChallenger))).to.be.null;
+            expect((await program.account.scamReport.fetch(scamReportPDA)).openChallenges).to.equal(0);
+
+            // Approve the report, thawing the asset and flagging the scammer
+            const moderatorKeypair = await fundedKeypair();
+            await program.methods
+                .addModerator(moderatorKeypair.publicKey)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+            await program.methods
+                .approveReport()
+                .accounts({
+                    authority: moderatorKeypair.publicKey,
+                    config: configPDA,
+                    asset,
+                    collection: collectionKeypair.publicKey,
+                    collectionRecord: null,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                })
+                .remainingAccounts(profileAccounts)
+                .signers([moderatorKeypair])
+                .rpc();
+            expect((await program.account.scammerProfile.fetch(profilePDA)).approvedReportCount.toNumber())
+                .to.equal(1);
+
+            // An upheld challenge retracts the report and refunds the bond and the challenge's rent
+            const winningChallenger = await fundedKeypair();
+            await openChallenge(winningChallenger, bond);
+            const challengeLamports = await provider.connection.getBalance(challengePDA(winningChallenger));
+            const challengerBefore = await provider.connection.getBalance(winningChallenger.publicKey);
+            await resolveChallenge(winningChallenger, true);
+            const challengerAfter = await provider.connection.getBalance(winningChallenger.publicKey);
+            expect(challengerAfter - challengerBefore).to.equal(challengeLamports);
+
+            const scamReport = await program.account.scamReport.fetch(scamReportPDA);
+            expect(scamReport.status).to.deep.equal({ retracted: {} });
+            const onChain = await fetchAsset(umi, asset.toString());
+            expect(onChain.attributes.attributeList.find(a => a.key === "report_status").value)
+                .to.equal("Retracted");
+            // The approved asset is frozen again, and the report no longer counts against the scammer
+            expect(onChain.permanentFreezeDelegate.frozen).to.be.true;
+            const profile = await program.account.scammerProfile.fetch(profilePDA);
+            expect(profile.reportCount.toNumber()).to.equal(0);
+            expect(profile.approvedReportCount.toNumber()).to.equal(0);
+            expect(profile.linkedAssets).to.be.empty;
+
+            // Retracted reports can't be challenged again
+            try {
+                await openChallenge(cheapChallenger, bond);
+                expect.fail("Transaction should have failed with InvalidChallenge error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidChallenge");
+            }
+        } catch (error) {
+            console.error("Error testing challenges:", error);
+            throw error;
+        }
+    });
+
+    it("Locks reporter stakes and slashes them when reports are rejected", async () => {
+        try {
+            const moderatorKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                moderatorKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+            await program.methods
+                .addModerator(moderatorKeypair.publicKey)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+
+            const minStake = new BN(200_000_000); // 0.2 SOL
+            await program.methods
+                .updateMinReportStake(minStake)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+
+            const mintStaked = async (stakeAmount: BN) => {
+                const nftKeypair = Keypair.generate();
+                const report = buildScamReport();
+                await program.methods
+                    .mintStandardNft(
+                        "Staked Report NFT",
+                        "https://example.com/staked-report.json",
+                        report,
+                        mintPayment,
+                        stakeAmount
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        standardCollection: collectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    .signers([nftKeypair])
+                    .rpc();
+                return nftKeypair.publicKey;
+            };
+            const reportStakePDA = (asset: PublicKey) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("report_stake"), asset.toBuffer()],
+                    program.programId
+                )[0];
+            const moderationAccounts = (asset: PublicKey) => ({
+                authority: moderatorKeypair.publicKey,
+                config: configPDA,
+                asset,
+                collection: collectionKeypair.publicKey,
+                collectionRecord: null,
+                updateAuthorityPda: updateAuthorityPDA,
+                mplCoreProgram: MPL_CORE_PROGRAM_ID,
+            });
+
+            // Once the config requires a stake, smaller stakes are rejected
+            try {
+                await mintStaked(noStake);
+                expect.fail("Transaction should have failed with InvalidStake error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidStake");
+            }
+
+            // A rejected report's stake goes to the anti-scam treasury
+            const rejectedAsset = await mintStaked(minStake);
+            const stake = await program.account.reportStake.fetch(reportStakePDA(rejectedAsset));
+            expect(stake.amount.toString()).to.equal(minStake.toString());
+            expect(stake.reporter.toString()).to.equal(provider.wallet.publicKey.toString());
+            expect(stake.status).to.deep.equal({ locked: {} });
+            expect(stake.unlockAt.toNumber() - stake.lockedAt.toNumber()).to.equal(30 * 24 * 60 * 60);
+
+            const antiScamBefore = await provider.connection.getBalance(antiScamTreasuryPDA);
+            await program.methods
+                .rejectReport({ spam: {} }, false)
+                .accounts(moderationAccounts(rejectedAsset))
+                .signers([moderatorKeypair])
+                .rpc();
+            const antiScamAfter = await provider.connection.getBalance(antiScamTreasuryPDA);
+            expect(antiScamAfter - antiScamBefore).to.equal(minStake.toNumber());
+            expect((await program.account.reportStake.fetch(reportStakePDA(rejectedAsset))).status)
+                .to.deep.equal({ slashed: {} });
+
+            // An approved report's stake stays locked until the lock period is over
+            const approvedAsset = await mintStaked(minStake);
+            await program.methods
+                .approveReport()
+                .accounts(moderationAccounts(approvedAsset))
+                .signers([moderatorKeypair])
+                .rpc();
+            try {
+                await program.methods
+                    .releaseStake()
+                    .accounts({
+                        reporter: provider.wallet.publicKey,
+                        reportStake: reportStakePDA(approvedAsset),
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with StakeLocked error");
+            } catch (error) {
+                expect(error.message).to.include("StakeLocked");
+            }
+
+            await program.methods
+                .updateMinReportStake(noStake)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+        } catch (error) {
+            console.error("Error testing report stakes:", error);
+            throw error;
+        }
+    });
+
+    it("Creates a seasonal collection in the registry and mints into it up to its max supply", async () => {
+        try {
+            const seasonalCollectionKeypair = Keypair.generate();
+            const [collectionRecordPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("collection_record"), seasonalCollectionKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            const settings = {
+                maxSupply: 1,
+                feeOverrides: { minimumPayment: null, treasuryFeePercent: 0 },
+                schemaId: 2,
+                active: true,
+            };
+
+            // Creating the collection into the registry also creates its record
+            await program.methods
+                .createCollection(
+                    "Seasonal Collection",
+                    "https://example.com/seasonal-collection.json",
+                    null,
+                    null,
+                    null,
+                    { registry: { kind: { standard: {} }, settings } }
+                )
+                .accounts({
+                    collection: seasonalCollectionKeypair.publicKey,
+                    updateAuthority: updateAuthorityPDA,
+                    payer: provider.wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    collectionRecord: collectionRecordPDA,
+                })
+                .signers([seasonalCollectionKeypair])
+                .rpc();
+
+            let record = await program.account.collectionRecord.fetch(collectionRecordPDA);
+            expect(record.kind).to.deep.equal({ standard: {} });
+            expect(record.maxSupply).to.equal(1);
+            expect(record.schemaId).to.equal(2);
+            expect(record.feeOverrides.treasuryFeePercent).to.equal(0);
+            expect(record.minted.toNumber()).to.equal(0);
+
+            // A collection the program doesn't control can't be registered
+            try {
+                await program.methods
+                    .registerCollection({ standard: {} }, settings)
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        collection: Keypair.generate().publicKey,
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with InvalidCollection error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidCollection");
+            }
+
+            const mintIntoSeasonal = async () => {
+                const nftKeypair = Keypair.generate();
+                const report = buildScamReport();
+                await program.methods
+                    .mintIntoCollection(
+                        "Seasonal Report NFT",
+                        "https://example.com/seasonal-report.json",
+                        report,
+                        mintPayment,
+                        noStake
+                    )
+                    .accounts({
+                        user: provider.wallet.publicKey,
+                        ...getIncidentAccounts(report, provider.wallet.publicKey),
+                        ruggedNftMint: nftKeypair.publicKey,
+                        collection: seasonalCollectionKeypair.publicKey,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        treasury: treasuryPDA,
+                        antiscamTreasury: antiScamTreasuryPDA,
+                        mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                        systemProgram: SystemProgram.programId,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        config: configPDA,
+                        feeSchedule: feeSchedulePDA,
+                    })
+                    .signers([nftKeypair])
+                    .rpc();
+                return nftKeypair.publicKey;
+            };
+
+            const asset = await mintIntoSeasonal();
+            const assetData = await fetchAsset(umi, asset.toString());
+            expect(assetData.updateAuthority.address.toString())
+                .to.equal(seasonalCollectionKeypair.publicKey.toString());
+            record = await program.account.collectionRecord.fetch(collectionRecordPDA);
+            expect(record.minted.toNumber()).to.equal(1);
+
+            // Reports in registry collections are moderated like any other
+            const moderatorKeypair = Keypair.generate();
+            const airdropSig = await provider.connection.requestAirdrop(
+                moderatorKeypair.publicKey,
+                1 * LAMPORTS_PER_SOL
+            );
+            await provider.connection.confirmTransaction({
+                signature: airdropSig,
+                blockhash: (await provider.connection.getLatestBlockhash()).blockhash,
+                lastValidBlockHeight: (await provider.connection.getLatestBlockhash()).lastValidBlockHeight
+            });
+            await program.methods
+                .addModerator(moderatorKeypair.publicKey)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+            await program.methods
+                .approveReport()
+                .accounts({
+                    authority: moderatorKeypair.publicKey,
+                    config: configPDA,
+                    asset,
+                    collection: seasonalCollectionKeypair.publicKey,
+                    collectionRecord: collectionRecordPDA,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                })
+                .signers([moderatorKeypair])
+                .rpc();
+            const approvedData = await fetchAsset(umi, asset.toString());
+            expect(approvedData.permanentFreezeDelegate.frozen).to.equal(false);
+            expect(approvedData.attributes.attributeList.find(a => a.key === "report_status").value)
+                .to.equal("Approved");
+
+            // A supply limit below what was already minted is refused
+            try {
+                await program.methods
+                    .addCollectionPlugin({
+                        masterEdition: {
+                            maxSupply: 0,
+                            name: "Seasonal Edition",
+                            uri: "https://example.com/seasonal-edition.json",
+                        },
+                    })
+                    .accounts({
+                        admin: provider.wallet.publicKey,
+                        config: configPDA,
+                        collection: seasonalCollectionKeypair.publicKey,
+                        collectionRecord: collectionRecordPDA,
+                        updateAuthorityPda: updateAuthorityPDA,
+                        mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with InvalidCollection error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidCollection");
+            }
+
+            // The record's cap applies, not the config's
+            try {
+                await mintIntoSeasonal();
+                expect.fail("Transaction should have failed with MaxSupplyExceeded error");
+            } catch (error) {
+                expect(error.message).to.include("MaxSupplyExceeded");
+            }
+
+            // Deactivated collections stop taking mints
+            await program.methods
+                .updateCollectionRecord({ ...settings, maxSupply: null, active: false })
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                    collectionRecord: collectionRecordPDA,
+                })
+                .rpc();
+            try {
+                await mintIntoSeasonal();
+                expect.fail("Transaction should have failed with CollectionInactive error");
+            } catch (error) {
+                expect(error.message).to.include("CollectionInactive");
+            }
+        } catch (error) {
+            console.error("Error testing the collection registry:", error);
+            throw error;
+        }
+    });
+
+    it("Doesn't require victims minting into a scammed registry collection to stake", async () => {
+        try {
+            const victimCollectionKeypair = Keypair.generate();
+            const [victimRecordPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("collection_record"), victimCollectionKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            await program.methods
+                .createCollection(
+                    "Victim Stories Collection",
+                    "https://example.com/victim-stories.json",
+                    null,
+                    null,
+                    null,
+                    {
+                        registry: {
+                            kind: { scammed: {} },
+                            settings: {
+                                maxSupply: null,
+                                feeOverrides: { minimumPayment: null, treasuryFeePercent: null },
+                                schemaId: 4,
+                                active: true,
+                            },
+                        },
+                    }
+                )
+                .accounts({
+                    collection: victimCollectionKeypair.publicKey,
+                    updateAuthority: updateAuthorityPDA,
+                    payer: provider.wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    collectionRecord: victimRecordPDA,
+                })
+                .signers([victimCollectionKeypair])
+                .rpc();
+
+            // The config's minimum stake only applies to standard reports, like `mint_scammed_nft`
+            await program.methods
+                .updateMinReportStake(new BN(200_000_000))
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+
+            const nftKeypair = Keypair.generate();
+            const report = buildScamReport();
+            await program.methods
+                .mintIntoCollection(
+                    "Victim Story NFT",
+                    "https://example.com/victim-story.json",
+                    report,
+                    mintPayment,
+                    noStake
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    collection: victimCollectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .signers([nftKeypair])
+                .rpc();
+
+            const record = await program.account.collectionRecord.fetch(victimRecordPDA);
+            expect(record.minted.toNumber()).to.equal(1);
+
+            await program.methods
+                .updateMinReportStake(noStake)
+                .accounts({
+                    admin: provider.wallet.publicKey,
+                    config: configPDA,
+                })
+                .rpc();
+        } catch (error) {
+            console.error("Error testing scammed registry stakes:", error);
+            throw error;
+        }
+    });
+
+    it("Refuses to create a collection into a bound config slot", async () => {
+        const collectionAccounts = (collection: PublicKey) => ({
+            collection,
+            updateAuthority: updateAuthorityPDA,
+            payer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+            mplCoreProgram: MPL_CORE_PROGRAM_ID,
+            config: configPDA,
+        });
+
+        const replacementKeypair = Keypair.generate();
+        try {
+            await program.methods
+                .createCollection(
+                    "Replacement Collection",
+                    "https://example.com/replacement-collection.json",
+                    null,
+                    null,
+                    null,
+                    { standard: {} }
+                )
+                .accounts(collectionAccounts(replacementKeypair.publicKey))
+                .signers([replacementKeypair])
+                .rpc();
+            expect.fail("Transaction should have failed with CollectionSlotBound error");
+        } catch (error) {
+            expect(error.message).to.include("CollectionSlotBound");
+        }
+
+        const config = await program.account.config.fetch(configPDA);
+        expect(config.standardCollection.toString()).to.equal(collectionKeypair.publicKey.toString());
+
+        // Collections created before the registry can still be added to it
+        await program.methods
+            .registerCollection(
+                { scammed: {} },
+                {
+                    maxSupply: null,
+                    feeOverrides: { minimumPayment: null, treasuryFeePercent: null },
+                    schemaId: 1,
+                    active: false,
+                }
+            )
+            .accounts({
+                admin: provider.wallet.publicKey,
+                config: configPDA,
+                collection: scammedCollectionKeypair.publicKey,
+            })
+            .rpc();
+        const [scammedRecordPDA] = PublicKey.findProgramAddressSync(
+            [Buffer.from("collection_record"), scammedCollectionKeypair.publicKey.toBuffer()],
+            program.programId
+        );
+        const scammedRecord = await program.account.collectionRecord.fetch(scammedRecordPDA);
+        expect(scammedRecord.kind).to.deep.equal({ scammed: {} });
+        expect(scammedRecord.active).to.equal(false);
+    });
+
+    it("Updates a registered collection's metadata and plugins", async () => {
+        try {
+            const themedCollectionKeypair = Keypair.generate();
+            const [themedRecordPDA] = PublicKey.findProgramAddressSync(
+                [Buffer.from("collection_record"), themedCollectionKeypair.publicKey.toBuffer()],
+                program.programId
+            );
+            await program.methods
+                .createCollection(
+                    "Themed Collection",
+                    "https://example.com/themed-collection.json",
+                    10,
+                    "Themed Edition",
+                    "https://example.com/themed-edition.json",
+                    {
+                        registry: {
+                            kind: { standard: {} },
+                            settings: {
+                                maxSupply: null,
+                                feeOverrides: { minimumPayment: null, treasuryFeePercent: null },
+                                schemaId: 3,
+                                active: true,
+                            },
+                        },
+                    }
+                )
+                .accounts({
+                    collection: themedCollectionKeypair.publicKey,
+                    updateAuthority: updateAuthorityPDA,
+                    payer: provider.wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    collectionRecord: themedRecordPDA,
+                })
+                .signers([themedCollectionKeypair])
+                .rpc();
+
+            const manageAccounts = {
+                admin: provider.wallet.publicKey,
+                config: configPDA,
+                collection: themedCollectionKeypair.publicKey,
+                collectionRecord: themedRecordPDA,
+                updateAuthorityPda: updateAuthorityPDA,
+                mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            };
+
+            await program.methods
+                .updateCollectionMetadata("Themed Collection: Winter", null)
+                .accounts(manageAccounts)
+                .rpc();
+            await program.methods
+                .addCollectionPlugin({
+                    attributes: { attributes: [{ key: "season", value: "winter" }] },
+                })
+                .accounts(manageAccounts)
+                .rpc();
+            await program.methods
+                .updateCollectionPlugin({
+                    attributes: { attributes: [{ key: "season", value: "spring" }] },
+                })
+                .accounts(manageAccounts)
+                .rpc();
+            await program.methods
+                .updateCollectionPlugin({
+                    masterEdition: {
+                        maxSupply: 20,
+                        name: "Themed Edition",
+                        uri: "https://example.com/themed-edition.json",
+                    },
+                })
+                .accounts(manageAccounts)
+                .rpc();
+
+            let collection = await fetchCollection(umi, themedCollectionKeypair.publicKey.toString());
+            expect(collection.name).to.equal("Themed Collection: Winter");
+            expect(collection.uri).to.equal("https://example.com/themed-collection.json");
+            expect(collection.attributes.attributeList).to.deep.equal([{ key: "season", value: "spring" }]);
+            expect(collection.masterEdition.maxSupply).to.equal(20);
+            // The registry entry follows the MasterEdition supply limit
+            expect((await program.account.collectionRecord.fetch(themedRecordPDA)).maxSupply).to.equal(20);
+
+            await program.methods
+                .removeCollectionPlugin({ attributes: {} })
+                .accounts(manageAccounts)
+                .rpc();
+            collection = await fetchCollection(umi, themedCollectionKeypair.publicKey.toString());
+            expect(collection.attributes).to.be.undefined;
+
+            // Collections outside the config slots and the registry can't be managed
+            try {
+                await program.methods
+                    .updateCollectionMetadata("Not Ours", null)
+                    .accounts({
+                        ...manageAccounts,
+                        collection: Keypair.generate().publicKey,
+                        collectionRecord: null,
+                    })
+                    .rpc();
+                expect.fail("Transaction should have failed with InvalidCollection error");
+            } catch (error) {
+                expect(error.message).to.include("InvalidCollection");
+            }
+        } catch (error) {
+            console.error("Error managing collection metadata:", error);
+            throw error;
+        }
+    });
+
+    it("Updates collection royalties with a program deny list", async () => {
+        const royaltyAccounts = {
+            admin: provider.wallet.publicKey,
+            config: configPDA,
+            collection: collectionKeypair.publicKey,
+            updateAuthorityPda: updateAuthorityPDA,
+            mplCoreProgram: MPL_CORE_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+        };
+        const blockedMarketplace = Keypair.generate().publicKey;
+
+        // Creator shares that don't add up to 100% are rejected before reaching mpl-core
+        try {
+            await program.methods
+                .updateCollectionRoyalties(
+                    500,
+                    [
+                        { address: treasuryPDA, percentage: 50 },
+                        { address: antiScamTreasuryPDA, percentage: 40 },
+                    ],
+                    { none: {} }
+                )
+                .accounts(royaltyAccounts)
+                .rpc();
+            expect.fail("Transaction should have failed with InvalidRoyalties error");
+        } catch (error) {
+            expect(error.message).to.include("InvalidRoyalties");
+        }
+
+        // So is an allow list that would block every transfer
+        try {
+            await program.methods
+                .updateCollectionRoyalties(
+                    500,
+                    [{ address: antiScamTreasuryPDA, percentage: 100 }],
+                    { programAllowList: { 0: [] } }
+                )
+                .accounts(royaltyAccounts)
+                .rpc();
+            expect.fail("Transaction should have failed with InvalidRoyalties error");
+        } catch (error) {
+            expect(error.message).to.include("InvalidRoyalties");
+        }
+
+        await program.methods
+            .updateCollectionRoyalties(
+                500,
+                [
+                    { address: treasuryPDA, percentage: 50 },
+                    { address: antiScamTreasuryPDA, percentage: 50 },
+                ],
+                { programDenyList: { 0: [blockedMarketplace] } }
+            )
+            .accounts(royaltyAccounts)
+            .rpc();
+
+        const collection = await fetchCollection(umi, collectionKeypair.publicKey.toString());
+        expect(collection.royalties.basisPoints).to.equal(500);
+        expect(collection.royalties.creators).to.have.length(2);
+        expect(collection.royalties.ruleSet.__kind).to.equal("ProgramDenyList");
+    });
+
+    it("Routes part of a scammed asset's royalties to the victim", async () => {
+        const adminAccounts = {
+            admin: provider.wallet.publicKey,
+            config: configPDA,
+        };
+
+        // The victim's share can't exceed 100%
+        try {
+            await program.methods
+                .updateVictimRoyalties(500, 101)
+                .accounts(adminAccounts)
+                .rpc();
+            expect.fail("Transaction should have failed with InvalidRoyalties error");
+        } catch (error) {
+            expect(error.message).to.include("InvalidRoyalties");
+        }
+
+        try {
+            await program.methods
+                .updateVictimRoyalties(500, 70)
+                .accounts(adminAccounts)
+                .rpc();
+
+            const nftKeypair = Keypair.generate();
+            const report = buildScamReport();
+            await program.methods
+                .mintScammedNft(
+                    "Victim Royalties NFT",
+                    "https://example.com/victim-royalties.json",
+                    "Liquidity pulled a day after launch",
+                    report,
+                    mintPayment,
+                    null,
+                    null
+                )
+                .accounts({
+                    user: provider.wallet.publicKey,
+                    ...getIncidentAccounts(report, provider.wallet.publicKey),
+                    ruggedNftMint: nftKeypair.publicKey,
+                    scammedCollection: scammedCollectionKeypair.publicKey,
+                    updateAuthorityPda: updateAuthorityPDA,
+                    treasury: treasuryPDA,
+                    antiscamTreasury: antiScamTreasuryPDA,
+                    mintTracker: getMintTrackerPDA(nftKeypair.publicKey)[0],
+                    systemProgram: SystemProgram.programId,
+                    mplCoreProgram: MPL_CORE_PROGRAM_ID,
+                    config: configPDA,
+                    feeSchedule: feeSchedulePDA,
+                })
+                .signers([nftKeypair])
+                .rpc();
+
+            // The asset's own Royalties plugin names the minter and the anti-scam treasury
+            const asset = await fetchAsset(umi, nftKeypair.publicKey.toString());
+            expect(asset.royalties.basisPoints).to.equal(500);
+            const shares = Object.fromEntries(
+                asset.royalties.creators.map((c) => [c.address.toString(), c.percentage])
+            );
+            expect(shares).to.deep.equal({
+                [provider.wallet.publicKey.toString()]: 70,
+                [antiScamTreasuryPDA.toString()]: 30,
+            });
+
+            // Later scammed mints go back to the collection's royalties
+            await program.methods
+                .updateVictimRoyalties(0, 0)
+                .accounts(adminAccounts)
+                .rpc();
+        } catch (error) {
+            console.error("Error testing victim royalties:", error);
+            throw error;
+        }
+    });
+});